@@ -0,0 +1,82 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Generates one test per example file in `src/<year>/examples/NN/`, see `advent_of_code::example_tests!`.
+ */
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+#[path = "src/example_names.rs"]
+mod example_names;
+
+/// Mirrors `advent_of_code::LEGACY_YEAR`, whose examples live in `src/examples/`.
+const LEGACY_YEAR: u16 = 2022;
+
+fn test_name(file_stem: &str) -> String {
+    let name: String = file_stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("example_{}", name)
+}
+
+/// The examples directory of every year, i.e. every `src/<year>/examples/`.
+fn example_dirs() -> Vec<(u16, PathBuf)> {
+    let src = Path::new("src");
+    let mut dirs: Vec<(u16, PathBuf)> = fs::read_dir(src)
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .filter(|year: &u16| *year != LEGACY_YEAR)
+        .map(|year| (year, src.join(year.to_string()).join("examples")))
+        .collect();
+
+    dirs.push((LEGACY_YEAR, src.join("examples")));
+    dirs
+}
+
+fn main() {
+    // a new year's examples are picked up once its first day is registered in `src/days.rs`.
+    println!("cargo:rerun-if-changed=src/days.rs");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    for (year, examples_dir) in example_dirs() {
+        if examples_dir.exists() {
+            println!("cargo:rerun-if-changed={}", examples_dir.display());
+        }
+        for day in 1..=25 {
+            write_tests(&out_dir, &examples_dir, year, day);
+        }
+    }
+}
+
+fn write_tests(out_dir: &Path, examples_dir: &Path, year: u16, day: u8) {
+    let names = example_names::list(&examples_dir.join(format!("{:02}", day)));
+
+    let tests: String = names
+        .iter()
+        .map(|name| {
+            format!(
+                "#[test]\nfn {}() {{\n    advent_of_code::examples::check({}, {}, {:?}, part_one, part_two);\n}}\n",
+                test_name(name),
+                year,
+                day,
+                name
+            )
+        })
+        .collect();
+
+    fs::write(out_dir.join(format!("{}-{}.rs", year, day)), tests).unwrap();
+}
@@ -0,0 +1,132 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::{env, fs, io};
+
+/// Whether an answer matches the known-good answer in `src/<year>/answers/NN.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    Match,
+    Mismatch,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Verdict::Match => "✅",
+            Verdict::Mismatch => "❌",
+            Verdict::Unknown => "❔",
+        }
+    }
+}
+
+/// Parses an answers file. Part 1 and part 2 are separated by a blank line,
+/// so multi-line answers such as day 10's screen output can be stored as-is.
+/// An unknown part 1 is left empty, i.e. the file starts with the blank line.
+pub fn parse_answers(contents: &str) -> [Option<String>; 2] {
+    let contents = contents.replace("\r\n", "\n");
+    let mut blocks = contents
+        .split("\n\n")
+        .map(|block| Some(block.trim().to_string()).filter(|b| !b.is_empty()));
+
+    [blocks.next().flatten(), blocks.next().flatten()]
+}
+
+/// Formats answers as read by [`parse_answers`].
+pub fn format_answers(answers: &[Option<String>; 2]) -> String {
+    match answers {
+        [part_one, None] => format!("{}\n", part_one.as_deref().unwrap_or_default()),
+        [part_one, Some(part_two)] => format!(
+            "{}\n\n{}\n",
+            part_one.as_deref().unwrap_or_default(),
+            part_two
+        ),
+    }
+}
+
+/// Stores `answer` as the known-good answer for `part` of `day`.
+pub fn write_answer(year: u16, day: u8, part: u8, answer: &str) -> io::Result<()> {
+    let mut answers = read_answers(year, day);
+    answers[usize::from(part.clamp(1, 2)) - 1] = Some(answer.trim().to_string());
+
+    let path = crate::input_path("answers", year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format_answers(&answers))
+}
+
+/// Reads the known-good answers of `day`. Parts without a stored answer are `None`.
+pub fn read_answers(year: u16, day: u8) -> [Option<String>; 2] {
+    fs::read_to_string(crate::input_path("answers", year, day))
+        .map(|contents| parse_answers(&contents))
+        .unwrap_or_default()
+}
+
+pub fn check(expected: Option<&str>, answer: Option<&str>) -> Verdict {
+    match (expected, answer) {
+        (None, _) => Verdict::Unknown,
+        (Some(expected), Some(answer)) if expected == answer.trim() => Verdict::Match,
+        (Some(_), _) => Verdict::Mismatch,
+    }
+}
+
+/// Checks `answer` against the stored answer for `part` of `day`.
+pub fn verify(year: u16, day: u8, part: u8, answer: Option<&str>) -> Verdict {
+    let expected = read_answers(year, day);
+    let expected = match part {
+        1 => expected[0].as_deref(),
+        2 => expected[1].as_deref(),
+        _ => None,
+    };
+    check(expected, answer)
+}
+
+/// Whether `--verify` was passed, which makes runners exit non-zero on a mismatch.
+pub fn verify_requested() -> bool {
+    env::args().skip(1).any(|arg| arg == "--verify")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("24000\n\n45000\n"),
+            [Some("24000".into()), Some("45000".into())]
+        );
+        assert_eq!(parse_answers("24000\n"), [Some("24000".into()), None]);
+        assert_eq!(
+            parse_answers("13140\n\n##..#\n#..##\n"),
+            [Some("13140".into()), Some("##..#\n#..##".into())]
+        );
+        assert_eq!(parse_answers("\n\n45000\n"), [None, Some("45000".into())]);
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn test_format_answers() {
+        for answers in [
+            [Some("24000".to_string()), Some("45000".to_string())],
+            [Some("24000".to_string()), None],
+            [None, Some("##..#\n#..##".to_string())],
+            [None, None],
+        ] {
+            assert_eq!(parse_answers(&format_answers(&answers)), answers);
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("7"), Some("7")), Verdict::Match);
+        assert_eq!(check(Some("7"), Some("8")), Verdict::Mismatch);
+        assert_eq!(check(Some("7"), None), Verdict::Mismatch);
+        assert_eq!(check(None, Some("7")), Verdict::Unknown);
+    }
+}
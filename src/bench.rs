@@ -0,0 +1,200 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::process;
+use std::time::{Duration, Instant};
+
+/// Settings for `--bench` runs, read from the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Runs before measuring that are discarded.
+    pub warmup: u32,
+    /// Maximum number of measured runs.
+    pub samples: u32,
+    /// Stop sampling once this much time was spent on a part, even if `samples` was not reached.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            samples: 100,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Returns a config if `--bench` was passed, honoring `--warmup <n>`, `--samples <n>` and `--budget-ms <ms>`.
+    /// Exits with an error message if one of them is invalid.
+    pub fn from_args() -> Option<Self> {
+        match Self::parse(pico_args::Arguments::from_env()) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    /// The flags that [`BenchConfig::from_args`] reads back into this config.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--bench".to_string(),
+            "--warmup".to_string(),
+            self.warmup.to_string(),
+            "--samples".to_string(),
+            self.samples.to_string(),
+            "--budget-ms".to_string(),
+            self.budget.as_millis().to_string(),
+        ]
+    }
+
+    fn parse(mut args: pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        if !args.contains("--bench") {
+            return Ok(None);
+        }
+
+        let default = BenchConfig::default();
+        let warmup = args.opt_value_from_str("--warmup")?;
+        let samples: Option<u32> = args.opt_value_from_str("--samples")?;
+        let budget = args.opt_value_from_str("--budget-ms")?;
+
+        Ok(Some(BenchConfig {
+            warmup: warmup.unwrap_or(default.warmup),
+            samples: samples.unwrap_or(default.samples).max(1),
+            budget: budget.map_or(default.budget, Duration::from_millis),
+        }))
+    }
+}
+
+/// Timing statistics over all measured runs of a part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl BenchStats {
+    /// Computes statistics over `timings`, which must not be empty.
+    pub fn from_timings(timings: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = timings.iter().map(|t| t.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let n = nanos.len();
+        let median = if n % 2 == 1 {
+            nanos[n / 2]
+        } else {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2
+        };
+
+        let mean = nanos.iter().map(|&t| t as f64).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos
+                .iter()
+                .map(|&t| (t as f64 - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        BenchStats {
+            samples: n,
+            min_ns: nanos[0],
+            median_ns: median,
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+/// Runs `func` repeatedly on `input` according to `config` and returns the last answer with its timings.
+pub fn bench<T>(func: impl Fn(&str) -> T, input: &str, config: &BenchConfig) -> (T, BenchStats) {
+    for _ in 0..config.warmup {
+        func(input);
+    }
+
+    let started = Instant::now();
+    let mut timings = vec![];
+    let mut result = None;
+
+    while timings.len() < config.samples as usize
+        && (timings.is_empty() || started.elapsed() < config.budget)
+    {
+        let timer = Instant::now();
+        result = Some(func(input));
+        timings.push(timer.elapsed());
+    }
+
+    // the loop runs at least once.
+    (result.unwrap(), BenchStats::from_timings(&timings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_timings() {
+        let timings = [40, 10, 30, 20].map(Duration::from_nanos);
+        assert_eq!(
+            BenchStats::from_timings(&timings),
+            BenchStats {
+                samples: 4,
+                min_ns: 10,
+                median_ns: 25,
+                mean_ns: 25,
+                stddev_ns: 13,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = |args: &[&str]| {
+            BenchConfig::parse(pico_args::Arguments::from_vec(
+                args.iter().map(Into::into).collect(),
+            ))
+        };
+
+        assert_eq!(args(&["--samples", "10"]).unwrap(), None);
+        assert_eq!(
+            args(&["--bench", "--samples", "10", "--budget-ms", "50"]).unwrap(),
+            Some(BenchConfig {
+                samples: 10,
+                budget: Duration::from_millis(50),
+                ..BenchConfig::default()
+            })
+        );
+        assert!(args(&["--bench", "--samples", "ten"]).is_err());
+        assert!(args(&["--bench", "--warmup", "-1"]).is_err());
+
+        let config = BenchConfig {
+            warmup: 1,
+            samples: 20,
+            budget: Duration::from_millis(250),
+        };
+        let forwarded = config.to_args();
+        let forwarded: Vec<&str> = forwarded.iter().map(|arg| arg.as_str()).collect();
+        assert_eq!(args(&forwarded).unwrap(), Some(config));
+    }
+
+    #[test]
+    fn test_bench_respects_budget() {
+        let config = BenchConfig {
+            warmup: 0,
+            samples: u32::MAX,
+            budget: Duration::from_millis(5),
+        };
+        let (result, stats) = bench(|input| Some(input.len()), "abc", &config);
+        assert_eq!(result, Some(3));
+        assert!(stats.samples > 0 && stats.samples < u32::MAX as usize);
+    }
+}
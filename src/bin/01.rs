@@ -1,36 +1,33 @@
-pub fn part_one(input: &str) -> Option<u32> {
-    let nums = split_and_parse(input);
-    match nums {
-        Ok(nums) => nums.iter().max().copied(),
-        Err(_) => None,
-    }
+use advent_of_code::helpers::parse::{self, ParseError, Span};
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let nums = split_and_parse(input)?;
+    nums.iter()
+        .max()
+        .copied()
+        .ok_or_else(|| Span::new(input).error("expected at least one elf"))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let res = split_and_parse(input);
-    match res {
-        Ok(res) if res.len() > 2 => {
-            let nums: Vec<u32> = res.to_vec();
-            let sum = nums.into_iter().rev().take(3).sum::<u32>();
-            Some(sum)
-        }
-        _ => None,
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let nums = split_and_parse(input)?;
+    if nums.len() < 3 {
+        return Err(
+            Span::new(input).error(format!("expected at least 3 elves, found {}", nums.len()))
+        );
     }
+    Ok(nums.into_iter().rev().take(3).sum())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
+    let input = &advent_of_code::read_input(2022, 1);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
 
-fn split_and_parse(input: &str) -> Result<Vec<u32>, std::num::ParseIntError> {
-    let result: Result<Vec<u32>, std::num::ParseIntError> = input
-        .split("\n\n")
+fn split_and_parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    parse::blocks(input)
         .map(|elf| elf.lines().map(|line| line.parse::<u32>()).sum())
-        .collect();
-
-    result
+        .collect()
 }
 
 #[cfg(test)]
@@ -40,24 +37,30 @@ mod tests {
     #[test]
     fn test_invalid_input() {
         let input = "invalid";
-        assert_eq!(part_one(input), None);
+        assert_eq!(
+            part_one(input).unwrap_err().to_string(),
+            "line 1, column 1: can't parse \"invalid\" as u32: invalid digit found in string"
+        );
     }
 
     #[test]
     fn test_too_few_input_values() {
         let input = "100\n100\n\n200\n200";
-        assert_eq!(part_two(input), None);
+        assert_eq!(
+            part_two(input).unwrap_err().to_string(),
+            "line 1, column 1: expected at least 3 elves, found 2"
+        );
     }
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_one(&input), Some(24000));
+        let input = advent_of_code::read_file("examples", 2022, 1);
+        assert_eq!(part_one(&input), Ok(24000));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_two(&input), Some(45000));
+        let input = advent_of_code::read_file("examples", 2022, 1);
+        assert_eq!(part_two(&input), Ok(45000));
     }
 }
@@ -12,7 +12,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 pub fn part_two(input: &str) -> Option<u32> {
     let mut sum_of_all_values = 0;
-    for chunk in &input.lines().into_iter().chunks(3) {
+    for chunk in &input.lines().chunks(3) {
         let els: Vec<HashSet<char>> = chunk.map(|it| it.chars().clone().collect()).collect();
 
         let hash_intersection = els[1..].iter().fold(els[0].clone(), |acc, xs| {
@@ -26,8 +26,8 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 pub fn calculate_value(intersection: Vec<char>) -> usize {
-    let lower_chars = ('a'..='z').into_iter().collect::<Vec<char>>();
-    let upper_chars = ('A'..='Z').into_iter().collect::<Vec<char>>();
+    let lower_chars = ('a'..='z').collect::<Vec<char>>();
+    let upper_chars = ('A'..='Z').collect::<Vec<char>>();
     let all_chars = [lower_chars, upper_chars].concat();
 
     let values: Vec<usize> = intersection
@@ -56,7 +56,7 @@ pub fn get_intersection_one_line(line: &str) -> Vec<char> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
+    let input = &advent_of_code::read_input(2022, 3);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -67,13 +67,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_one(&input), Some(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_two(&input), Some(70));
     }
 }
@@ -1,21 +1,22 @@
+use advent_of_code::helpers::parse::{self, ParseError, Span};
+use advent_of_code::helpers::regex;
 use itertools::Itertools;
-use regex::Regex;
 use std::collections::VecDeque;
 
-pub fn part_one(input: &str) -> Option<String> {
-    let mut harbor = Harbor::build(input);
+pub fn part_one(input: &str) -> Result<String, ParseError> {
+    let mut harbor = Harbor::build(input)?;
     harbor.apply_moves();
-    Some(harbor.get_top_of_stack())
+    Ok(harbor.get_top_of_stack())
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let mut harbor = Harbor::build(input);
+pub fn part_two(input: &str) -> Result<String, ParseError> {
+    let mut harbor = Harbor::build(input)?;
     harbor.apply_moves_9001();
-    Some(harbor.get_top_of_stack())
+    Ok(harbor.get_top_of_stack())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
+    let input = &advent_of_code::read_input(2022, 5);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -26,32 +27,31 @@ struct Harbor {
     moves: Vec<Move>,
 }
 impl Harbor {
-    fn build(input: &str) -> Harbor {
-        let (harbor_str, moves_str) = input.split("\n\n").next_tuple().unwrap();
+    fn build(input: &str) -> Result<Harbor, ParseError> {
+        let (harbor_str, moves_str) = parse::blocks(input).next_tuple().ok_or_else(|| {
+            Span::new(input).error("expected the stacks and the moves, separated by a blank line")
+        })?;
 
         let last_line = harbor_str.lines().last().unwrap();
-        let n_stacks: usize = last_line
-            .split_whitespace()
+        let n_stacks: usize = *last_line
+            .numbers()?
             .last()
-            .unwrap()
-            .parse()
-            .unwrap();
+            .ok_or_else(|| last_line.error("expected the numbers of the stacks"))?;
 
         let mut stacks = vec![VecDeque::new(); n_stacks];
 
-        for line in harbor_str.lines() {
-            let re = Regex::new(r"\[[A-Z]\]").unwrap();
+        for line in harbor_str.as_str().lines() {
             for z in line.chars().chunks(4).into_iter().zip(stacks.iter_mut()) {
                 let (chunk, stack) = z;
                 let item = chunk.collect::<String>();
-                if re.is_match(&item) {
+                if regex!(r"\[[A-Z]\]").is_match(&item) {
                     stack.push_front(item)
                 }
             }
         }
 
-        let moves: Vec<Move> = moves_str.lines().map(Move::from).collect();
-        Harbor { stacks, moves }
+        let moves = moves_str.lines().map(Move::parse).try_collect()?;
+        Ok(Harbor { stacks, moves })
     }
 
     fn apply_moves(&mut self) {
@@ -79,8 +79,7 @@ impl Harbor {
             .collect_vec()
             .concat();
 
-        let re = Regex::new(r"[^A-Z]").unwrap();
-        re.replace_all(&str, "").into()
+        regex!(r"[^A-Z]").replace_all(&str, "").into()
     }
 }
 
@@ -91,20 +90,20 @@ struct Move {
     to: usize,
 }
 
-impl From<&str> for Move {
-    fn from(input: &str) -> Self {
-        let re = Regex::new(r"\d+").unwrap();
-        let nums = re
-            .find_iter(input)
-            .filter_map(|digits| digits.as_str().parse().ok());
+impl Move {
+    fn parse(line: Span) -> Result<Self, ParseError> {
+        let [amount, from, to] = line.numbers_array::<usize, 3>()?;
+        let index = |stack: usize| {
+            stack
+                .checked_sub(1)
+                .ok_or_else(|| line.error("stacks are numbered from 1"))
+        };
 
-        let (amount, from, to) = nums.collect_tuple().unwrap();
-
-        Move {
+        Ok(Move {
             amount,
-            from: from - 1,
-            to: to - 1,
-        }
+            from: index(from)?,
+            to: index(to)?,
+        })
     }
 }
 
@@ -114,13 +113,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some("CMZ".into()));
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_one(&input), Ok("CMZ".into()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some("MCD".into()));
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_two(&input), Ok("MCD".into()));
     }
 }
@@ -44,7 +44,7 @@ fn parse<'a>(input: &mut impl Iterator<Item = &'a str>) -> Vec<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
+    let input = &advent_of_code::read_input(2022, 7);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -55,13 +55,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
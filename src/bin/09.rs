@@ -1,6 +1,6 @@
+use advent_of_code::helpers::Point;
 use std::{
     collections::{HashSet, VecDeque},
-    ops,
     str::FromStr,
     string,
 };
@@ -29,13 +29,13 @@ fn calc_n_visisted<const N: usize>(input: &str) -> u32 {
 
 #[derive(Debug, Clone)]
 struct Head {
-    pos: Coord,
+    pos: Point<i8>,
     moves: VecDeque<Direction>,
 }
 
 impl Head {
     fn new(input: &str) -> Self {
-        let pos = Coord { x: 0, y: 0 };
+        let pos = Point::ORIGIN;
         let moves = input
             .lines()
             .flat_map(|l| {
@@ -49,13 +49,13 @@ impl Head {
         Head { pos, moves }
     }
 
-    fn execute_move(&mut self) -> Coord {
+    fn execute_move(&mut self) -> Point<i8> {
         if let Some(mv) = self.moves.pop_front() {
-            match mv {
-                Direction::Up => self.pos = self.pos + Coord { x: 0, y: 1 },
-                Direction::Down => self.pos = self.pos + Coord { x: 0, y: -1 },
-                Direction::Right => self.pos = self.pos + Coord { x: 1, y: 0 },
-                Direction::Left => self.pos = self.pos + Coord { x: -1, y: 0 },
+            self.pos += match mv {
+                Direction::Up => Point::UP,
+                Direction::Down => Point::DOWN,
+                Direction::Right => Point::RIGHT,
+                Direction::Left => Point::LEFT,
             }
         }
 
@@ -65,60 +65,32 @@ impl Head {
 
 #[derive(Debug, Clone)]
 struct Tail {
-    pos: Coord,
-    visited: HashSet<Coord>,
+    pos: Point<i8>,
+    visited: HashSet<Point<i8>>,
 }
 
 impl Tail {
     fn new() -> Self {
-        let pos = Coord { x: 0, y: 0 };
+        let pos = Point::ORIGIN;
         Tail {
             pos,
             visited: HashSet::from([pos]),
         }
     }
 
-    fn follow(&mut self, head_pos: &Coord) -> Coord {
-        let dir = *head_pos - self.pos;
-        let is_touching = dir.x.abs() <= 1 && dir.y.abs() <= 1;
+    fn follow(&mut self, head_pos: &Point<i8>) -> Point<i8> {
+        let is_touching = self.pos.chebyshev(*head_pos) <= 1;
 
         if is_touching {
             self.pos
         } else {
-            let x = dir.x.signum();
-            let y = dir.y.signum();
-
-            self.pos = self.pos + Coord { x, y };
+            self.pos = self.pos.step_towards(*head_pos);
             self.visited.insert(self.pos);
             self.pos
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-struct Coord {
-    x: i8,
-    y: i8,
-}
-impl ops::Add<Coord> for Coord {
-    type Output = Coord;
-    fn add(self, other: Coord) -> Coord {
-        Coord {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
-    }
-}
-impl ops::Sub<Coord> for Coord {
-    type Output = Coord;
-    fn sub(self, other: Coord) -> Coord {
-        Coord {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
-    }
-}
-
 #[derive(Debug, Copy, Clone)]
 pub enum Direction {
     Up,
@@ -141,7 +113,7 @@ impl FromStr for Direction {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
+    let input = &advent_of_code::read_input(2022, 9);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -150,15 +122,5 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_one(&input), Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_two(&input), Some(1));
-    }
+    advent_of_code::example_tests!(2022, 9);
 }
@@ -7,7 +7,6 @@ pub fn part_one(input: &str) -> Option<i32> {
     let cycles = cpu.stack.len();
 
     let sum: i32 = (1..=cycles)
-        .into_iter()
         .filter_map(|i| match i as i32 {
             i if i % 40 == 20 => {
                 let val = i * cpu.reg;
@@ -81,7 +80,7 @@ impl Cpu {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
+    let input = &advent_of_code::read_input(2022, 10);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -92,13 +91,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
         let str = "#..##..##..##..##..##..##..##..##..##...\n##...###...###...###...###...###...###..\n###....####....####....####....####.....\n####.....#####.....#####.....#####......\n#####......######......######......####.\n######.......#######.......#######......";
         assert_eq!(part_two(&input), Some(str.to_string()));
     }
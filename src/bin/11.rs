@@ -1,10 +1,9 @@
+use advent_of_code::helpers::parse::{self, ParseError, Span};
 use itertools::Itertools;
-use regex::Regex;
 use std::collections::VecDeque;
 
-pub fn part_one(input: &str) -> Option<u128> {
-    let raw_monkeys: Vec<&str> = input.split("\n\n").collect();
-    let mut monkeys: Vec<Monkey> = raw_monkeys.iter().map(|m| Monkey::build(m)).collect();
+pub fn part_one(input: &str) -> Result<u128, ParseError> {
+    let mut monkeys: Vec<Monkey> = parse::blocks(input).map(Monkey::build).try_collect()?;
 
     for _ in 0..20 {
         for i in 0..monkeys.len() {
@@ -34,14 +33,13 @@ pub fn part_one(input: &str) -> Option<u128> {
         .collect_vec()
         .iter()
         .map(|m| m.count as u128)
-        .product::<u128>() as u128;
+        .product::<u128>();
 
-    Some(monkey_business)
+    Ok(monkey_business)
 }
 
-pub fn part_two(input: &str) -> Option<u128> {
-    let raw_monkeys: Vec<&str> = input.split("\n\n").collect();
-    let mut monkeys: Vec<Monkey> = raw_monkeys.iter().map(|m| Monkey::build(m)).collect();
+pub fn part_two(input: &str) -> Result<u128, ParseError> {
+    let mut monkeys: Vec<Monkey> = parse::blocks(input).map(Monkey::build).try_collect()?;
     let modulo = monkeys.iter().fold(1, |acc, monkey| {
         if acc % monkey.divisor == 0 {
             acc
@@ -78,13 +76,13 @@ pub fn part_two(input: &str) -> Option<u128> {
         .collect_vec()
         .iter()
         .map(|m| m.count as u128)
-        .product::<u128>() as u128;
+        .product::<u128>();
 
-    Some(monkey_business)
+    Ok(monkey_business)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
+    let input = &advent_of_code::read_input(2022, 11);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -107,59 +105,25 @@ struct Monkey {
 }
 
 impl Monkey {
-    fn build(input: &str) -> Self {
-        let items: VecDeque<u64> = Regex::new(r#"Starting items: (\d+(?:, \d+)*)\n"#)
-            .unwrap()
-            .captures(input)
-            .unwrap()[1]
-            .split(',')
-            .map(|it| it.trim().parse::<u64>().unwrap())
-            .collect();
-
-        let operation = input
-            .lines()
-            .find(|l| l.trim_start().starts_with("Operation: new = old "))
-            .unwrap()
-            .strip_prefix("  Operation: new = old ")
-            .unwrap()
-            .split_once(' ')
-            .map(|(o, x)| match (o, x) {
-                ("*", "old") => Operation::Square, // todo!
-                ("*", _) => Operation::Mult(x.parse::<u64>().unwrap()),
-                ("+", _) => Operation::Add(x.parse::<u64>().unwrap()),
-                _ => unreachable!(),
-            })
-            .unwrap();
-
-        let divisor = Regex::new(r#"Test: divisible by (\d*)"#)
-            .unwrap()
-            .captures(input)
-            .unwrap()[1]
-            .parse::<u64>()
-            .unwrap();
-
-        let true_target = Regex::new(r#"If true: throw to monkey (\d*)"#)
-            .unwrap()
-            .captures(input)
-            .unwrap()[1]
-            .parse::<u64>()
-            .unwrap();
-
-        let false_target = Regex::new(r#"If false: throw to monkey (\d*)"#)
-            .unwrap()
-            .captures(input)
-            .unwrap()[1]
-            .parse::<u64>()
-            .unwrap();
-
-        Monkey {
+    fn build(input: Span) -> Result<Self, ParseError> {
+        let items = input.after("Starting items:")?.numbers()?.into();
+
+        let (operator, operand) = input.after("Operation: new = old ")?.split_once(" ")?;
+        let operation = match (operator.as_str(), operand.as_str()) {
+            ("*", "old") => Operation::Square,
+            ("*", _) => Operation::Mult(operand.parse()?),
+            ("+", _) => Operation::Add(operand.parse()?),
+            _ => return Err(operator.error(format!("unknown operator \"{}\"", operator))),
+        };
+
+        Ok(Monkey {
             items,
-            divisor,
-            true_target,
-            false_target,
+            divisor: input.after("Test: divisible by ")?.parse()?,
+            true_target: input.after("If true: throw to monkey ")?.parse()?,
+            false_target: input.after("If false: throw to monkey ")?.parse()?,
             operation,
             count: 0,
-        }
+        })
     }
 }
 
@@ -169,13 +133,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_one(&input), Some(10605));
+        let input = advent_of_code::read_file("examples", 2022, 11);
+        assert_eq!(part_one(&input), Ok(10605));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_two(&input), Some(2713310158));
+        let input = advent_of_code::read_file("examples", 2022, 11);
+        assert_eq!(part_two(&input), Ok(2713310158));
     }
 }
@@ -57,7 +57,7 @@ impl FromStr for PathFinding {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 12);
+    let input = &advent_of_code::read_input(2022, 12);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -68,13 +68,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 12);
+        let input = advent_of_code::read_file("examples", 2022, 12);
         assert_eq!(part_one(&input), Some(31));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 12);
+        let input = advent_of_code::read_file("examples", 2022, 12);
         assert_eq!(part_two(&input), Some(29));
     }
 }
@@ -78,7 +78,7 @@ impl Ord for Data {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
+    let input = &advent_of_code::read_input(2022, 13);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -89,13 +89,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = advent_of_code::read_file("examples", 2022, 13);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = advent_of_code::read_file("examples", 2022, 13);
         assert_eq!(part_two(&input), Some(140));
     }
 
@@ -1,8 +1,8 @@
 use std::collections::VecDeque;
 
+use advent_of_code::helpers::{regex, Point};
 use fxhash::FxHashSet;
 use itertools::Itertools;
-use regex::{self, Regex};
 
 pub fn part_one(input: &str) -> Option<u32> {
     let use_floor = false;
@@ -16,7 +16,10 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(cave.into_iter().last().unwrap())
 }
 
-type Coord = (i32, i32);
+type Coord = Point<i32>;
+
+const SOURCE: Coord = Point::new(500, 0);
+
 #[derive(Debug)]
 struct Cave {
     obstacles: FxHashSet<Coord>,
@@ -28,36 +31,36 @@ struct Cave {
 
 impl Cave {
     fn new(input: &str, use_floor: bool) -> Self {
-        let re = Regex::new(r#"(\d+),(\d+)"#).unwrap();
-        let obstacles: FxHashSet<(i32, i32)> = FxHashSet::from_iter(input.lines().flat_map(|l| {
-            re.captures_iter(l)
+        let obstacles: FxHashSet<Coord> = FxHashSet::from_iter(input.lines().flat_map(|l| {
+            regex!(r"(\d+),(\d+)")
+                .captures_iter(l)
                 .map(|cap| {
-                    (
+                    Point::new(
                         cap[1].parse::<i32>().unwrap(),
                         cap[2].parse::<i32>().unwrap(),
                     )
                 })
                 .tuple_windows()
                 .flat_map(|(a, b)| {
-                    let x_range = if (a.0..=b.0).is_empty() {
-                        b.0..=a.0
+                    let x_range = if (a.x..=b.x).is_empty() {
+                        b.x..=a.x
                     } else {
-                        a.0..=b.0
+                        a.x..=b.x
                     };
-                    let y_range = if (a.1..=b.1).is_empty() {
-                        b.1..=a.1
+                    let y_range = if (a.y..=b.y).is_empty() {
+                        b.y..=a.y
                     } else {
-                        a.1..=b.1
+                        a.y..=b.y
                     };
-                    x_range.cartesian_product(y_range)
+                    x_range.cartesian_product(y_range).map(Point::from)
                 })
-                .collect::<Vec<(i32, i32)>>()
+                .collect::<Vec<Coord>>()
         }));
 
-        let max_depth = obstacles.iter().map(|c| c.1).max().unwrap();
+        let max_depth = obstacles.iter().map(|c| c.y).max().unwrap();
 
         let sand = Sand {
-            pos: (500, 0),
+            pos: SOURCE,
             visited: VecDeque::new(),
         };
 
@@ -90,20 +93,20 @@ impl Iterator for Cave {
         } = self;
 
         let sand_pos = sand.pos;
-        let new_sand_posses = vec![
-            (sand_pos.0, sand_pos.1 + 1),
-            (sand_pos.0 - 1, sand_pos.1 + 1),
-            (sand_pos.0 + 1, sand_pos.1 + 1),
+        let new_sand_posses = [
+            sand_pos + Point::DOWN,
+            sand_pos + Point::DOWN + Point::LEFT,
+            sand_pos + Point::DOWN + Point::RIGHT,
         ];
 
         let floor_y = *max_depth + 2;
 
         let new_sand_pos = new_sand_posses
             .iter()
-            .filter(|p| p.1 < floor_y)
+            .filter(|p| p.y < floor_y)
             .find(|el| !obstacles.contains(el));
 
-        if obstacles.contains(&(500, 0)) {
+        if obstacles.contains(&SOURCE) {
             return None;
         }
         match new_sand_pos {
@@ -114,13 +117,13 @@ impl Iterator for Cave {
                 let new_start = if visited.len() > 1 {
                     visited[1]
                 } else {
-                    (500, 0)
+                    SOURCE
                 };
                 self.sand.pos = new_start;
                 self.sand.visited.clear();
             }
             Some(new_sand_pos) => {
-                if !*use_floor && new_sand_pos.1 > *max_depth {
+                if !*use_floor && new_sand_pos.y > *max_depth {
                     return None;
                 }
                 sand.pos = *new_sand_pos;
@@ -134,7 +137,7 @@ impl Iterator for Cave {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
+    let input = &advent_of_code::read_input(2022, 14);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -145,13 +148,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = advent_of_code::read_file("examples", 2022, 14);
         assert_eq!(part_one(&input), Some(24));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = advent_of_code::read_file("examples", 2022, 14);
         assert_eq!(part_two(&input), Some(93));
     }
 }
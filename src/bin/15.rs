@@ -1,5 +1,5 @@
+use advent_of_code::helpers::{regex, Point};
 use itertools::Itertools;
-use regex::Regex;
 
 pub fn part_one(input: &str) -> Option<usize> {
     let y: i64 = 2000000;
@@ -8,7 +8,7 @@ pub fn part_one(input: &str) -> Option<usize> {
 
     let min_max = sensors
         .iter()
-        .flat_map(|s| [s.pos.x - s.manh_dist as i64, s.pos.x + s.manh_dist as i64])
+        .flat_map(|s| [s.pos.x - s.manh_dist, s.pos.x + s.manh_dist])
         .minmax();
     let (lower, upper) = match min_max {
         itertools::MinMaxResult::MinMax(min, max) => (min, max),
@@ -27,7 +27,7 @@ pub fn part_two(input: &str) -> Option<i64> {
     let max: i64 = 4000000;
 
     sensors.iter().find_map(|s| {
-        ((s.pos.x - s.manh_dist as i64 - 1).max(0)..=s.pos.x.min(max))
+        ((s.pos.x - s.manh_dist - 1).max(0)..=s.pos.x.min(max))
             .zip(s.pos.y..=max)
             .find_map(|p| {
                 sensors
@@ -38,33 +38,23 @@ pub fn part_two(input: &str) -> Option<i64> {
     })
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct Point {
-    x: i64,
-    y: i64,
-}
 #[derive(Debug, Clone, Copy)]
 struct Sensor {
-    pos: Point,
-    closest_beacon: Point,
-    manh_dist: u32,
+    pos: Point<i64>,
+    closest_beacon: Point<i64>,
+    manh_dist: i64,
 }
 impl Sensor {
-    pub fn is_inside_range(&self, p: Point) -> bool {
+    pub fn is_inside_range(&self, p: Point<i64>) -> bool {
         if self.closest_beacon == p {
             return false;
         }
-        self.manh_dist
-            >= (self.pos.x.abs_diff(p.x) + self.pos.y.abs_diff(p.y))
-                .try_into()
-                .unwrap()
+        self.manh_dist >= self.pos.manhattan(p)
     }
 }
 
 fn parse_sensors(input: &str) -> Vec<Sensor> {
-    let re =
-        Regex::new(r#"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)"#)
-            .unwrap();
+    let re = regex!(r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)");
     input
         .lines()
         .map(|l| {
@@ -78,7 +68,7 @@ fn parse_sensors(input: &str) -> Vec<Sensor> {
                         x: c[3].parse().unwrap(),
                         y: c[4].parse().unwrap(),
                     };
-                    let manh_dist = manhattan_dist(&pos, &closest_beacon);
+                    let manh_dist = pos.manhattan(closest_beacon);
                     Sensor {
                         pos,
                         closest_beacon,
@@ -90,13 +80,8 @@ fn parse_sensors(input: &str) -> Vec<Sensor> {
         .collect()
 }
 
-#[inline]
-fn manhattan_dist(p: &Point, q: &Point) -> u32 {
-    ((p.x - q.x).abs() + (p.y - q.y).abs()) as u32
-}
-
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 15);
+    let input = &advent_of_code::read_input(2022, 15);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -107,13 +92,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 15);
+        let input = advent_of_code::read_file("examples", 2022, 15);
         assert_eq!(part_one(&input), Some(0));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 15);
+        let input = advent_of_code::read_file("examples", 2022, 15);
         assert_eq!(part_two(&input), Some(28000022));
     }
 }
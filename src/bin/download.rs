@@ -2,104 +2,248 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
+use advent_of_code::client::{Client, DEFAULT_BASE_URL};
+use advent_of_code::examples::{self, Example};
+use advent_of_code::manifest::Manifest;
+use advent_of_code::puzzle;
+use advent_of_code::DEFAULT_YEAR;
 use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: Option<u16>,
+    base_url: Option<String>,
+    puzzle: bool,
+    force: bool,
+    /// Index of the code block to extract into `examples/NN/`, if requested.
+    example: Option<usize>,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
         year: args.opt_value_from_str(["-y", "--year"])?,
+        base_url: args.opt_value_from_str("--base-url")?,
+        puzzle: args.contains("--puzzle"),
+        force: args.contains("--force"),
+        example: match args.opt_value_from_str("--example-block")? {
+            Some(block) => Some(block),
+            None => args.contains("--example").then_some(0),
+        },
+        day: args.free_from_str()?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
-}
-
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let client = match Client::from_env(args.base_url) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to set up client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let year = args.year.unwrap_or(DEFAULT_YEAR);
+
+    if client.base_url() != DEFAULT_BASE_URL {
+        println!("Using base url {}", client.base_url());
+    }
+
+    download_input(&client, year, args.day, args.force);
 
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
+    if !args.puzzle && args.example.is_none() {
+        return;
     }
 
-    let mut cmd_args = vec![];
+    println!("Downloading puzzle of day {} ({})...", args.day, year);
+
+    let html = match client.get(year, args.day, "") {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("Failed to download puzzle: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.puzzle {
+        write_puzzle(&html, client.base_url(), year, args.day);
+    }
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    if let Some(block) = args.example {
+        write_example(&html, block, year, args.day, args.force);
     }
+}
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
+fn download_input(client: &Client, year: u16, day: u8, force: bool) {
+    let input_path = advent_of_code::input_path("inputs", year, day);
+    let mut manifest = match Manifest::load(year) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("could not read input manifest: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // inputs never change once published, so only download them again when asked to.
+    if let Ok(existing) = fs::read_to_string(&input_path) {
+        if !existing.is_empty() && !force {
+            match manifest.check(day, &existing) {
+                Some(modification) => println!(
+                    "⚠️  The input of day {} differs from the downloaded one: {}.",
+                    day, modification
+                ),
+                None if manifest.get(day).is_none() => println!(
+                    "The input of day {} was not downloaded by this tool, its integrity can't be checked.",
+                    day
+                ),
+                None => {}
             }
+            println!(
+                "Input of day {} already exists at \"{}\", pass --force to download it again.",
+                day,
+                input_path.display()
+            );
+            return;
         }
+    }
+
+    println!("Downloading input of day {} ({})...", day, year);
+
+    let input = match client.get_input(year, day) {
+        Ok(input) => input,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to download input: {}", e);
+            process::exit(1);
         }
+    };
+
+    let written = input_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&input_path, &input));
+    if let Err(e) = written {
+        eprintln!("could not write input file: {}", e);
+        process::exit(1);
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+    manifest.record(day, &input);
+    if let Err(e) = manifest.save(year) {
+        eprintln!("could not write input manifest: {}", e);
+        process::exit(1);
+    }
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+}
+
+fn write_puzzle(html: &str, base_url: &str, year: u16, day: u8) {
+    let markdown = match puzzle::to_markdown(html, base_url) {
+        Some(markdown) => markdown,
+        None => {
+            eprintln!("Failed to find the puzzle description in the downloaded page.");
+            process::exit(1);
         }
+    };
+
+    let puzzle_path = puzzle::puzzle_path(year, day);
+    let written = puzzle_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&puzzle_path, markdown));
+
+    match written {
+        Ok(_) => println!(
+            "🎄 Successfully wrote puzzle to \"{}\".",
+            puzzle_path.display()
+        ),
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("could not write puzzle file: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn write_example(html: &str, block: usize, year: u16, day: u8, force: bool) {
+    let blocks = puzzle::code_blocks(html);
+    let example = match blocks.get(block) {
+        Some(example) => example,
+        None => {
+            eprintln!(
+                "Failed to find code block {} in the puzzle, it only has {}.",
+                block,
+                blocks.len()
+            );
+            process::exit(1);
         }
+    };
+
+    let name = match block {
+        0 => "example".to_string(),
+        block => format!("example_{}", block),
+    };
+    let example_path = examples::example_path(year, day, &name);
+    if example_path.exists() && !force {
+        println!(
+            "Example of day {} already exists at \"{}\", pass --force to overwrite it.",
+            day,
+            example_path.display()
+        );
+        return;
+    }
+
+    let answers = puzzle::example_answers(html);
+    let [part_one, part_two] = answers.clone();
+    let example = Example {
+        input: example.to_string(),
+        part_one,
+        part_two,
+    };
+    let written = example_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&example_path, example.to_string()));
+    if let Err(e) = written {
+        eprintln!("could not write example file: {}", e);
+        process::exit(1);
+    }
+    println!(
+        "🎄 Successfully wrote example to \"{}\".",
+        example_path.display()
+    );
+
+    // seed the tests that `cargo scaffold` generated with the emphasized answers.
+    let module_path = advent_of_code::module_path(year, day);
+    let mut module = match fs::read_to_string(&module_path) {
+        Ok(module) => module,
+        Err(_) => return,
+    };
+
+    for (solver, answer) in ["part_one", "part_two"].iter().zip(answers) {
+        let answer = match answer {
+            Some(answer) => answer,
+            None => continue,
+        };
+        if let Some(seeded) = puzzle::seed_test_assertion(&module, solver, &answer) {
+            module = seeded;
+            println!(
+                "🎄 Expecting {} for the example in `{}`'s test, please double-check.",
+                answer, solver
+            );
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("could not update module file: {}", e);
+        process::exit(1);
     }
 }
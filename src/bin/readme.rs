@@ -0,0 +1,159 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{self, Verdict};
+use advent_of_code::history::{self, History, HISTORY_FILE};
+use advent_of_code::readme::{self, DayStatus};
+use advent_of_code::worker::{self, DayError, WorkerOptions};
+use advent_of_code::{days, DEFAULT_YEAR};
+use std::{fs, process, time::Duration};
+
+struct Args {
+    year: u16,
+    dry_run: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        dry_run: args.contains("--dry-run"),
+    })
+}
+
+/// A part earns its star if it has a stored answer that the solver still reproduces.
+/// Days that can't be run here, e.g. because the input is missing or the solution panics, keep the stars
+/// of their stored answers, and parts without a stored answer keep the star they have in the current table (`listed`).
+fn day_status(
+    year: u16,
+    day: u8,
+    history: &History,
+    listed: Option<[bool; 2]>,
+    options: &WorkerOptions,
+) -> Option<DayStatus> {
+    let solution = days::SOLUTIONS
+        .iter()
+        .find(|s| s.year == year && s.day == day);
+    let stored = answers::read_answers(year, day);
+
+    // each day runs in a worker process, so a panicking or hanging solution can't take this down.
+    let run = solution
+        .filter(|_| stored.iter().any(Option::is_some))
+        .map(|solution| worker::run_day(solution, options));
+    if let Some(error) = run.as_ref().and_then(|run| run.error.as_ref()) {
+        if !matches!(error, DayError::Input(_)) {
+            eprintln!(
+                "⚠️  Day {}: {}, keeping the stars of its stored answers.",
+                day,
+                error.message()
+            );
+        }
+    }
+
+    let mut stars = [false; 2];
+    for part in [1, 2] {
+        let index = usize::from(part) - 1;
+        let result = run
+            .as_ref()
+            .and_then(|run| run.results.iter().find(|r| r.part == part));
+        stars[index] = match (&stored[index], result) {
+            (None, _) => listed.is_some_and(|stars| stars[index]),
+            (Some(_), Some(result)) => {
+                if result.verdict == Some(Verdict::Mismatch) {
+                    eprintln!(
+                        "⚠️  Day {} part {} no longer matches its stored answer, leaving out its star.",
+                        day, part
+                    );
+                }
+                result.verdict == Some(Verdict::Match)
+            }
+            (Some(_), None) => true,
+        };
+    }
+
+    let timings = [1, 2].map(|part| {
+        history
+            .latest(year, day, part)
+            .map(|entry| Duration::from_nanos(entry.elapsed_ns))
+    });
+
+    if solution.is_none() && listed.is_none() && stars == [false; 2] && timings == [None; 2] {
+        return None;
+    }
+    Some(DayStatus {
+        day,
+        stars,
+        timings,
+    })
+}
+
+fn main() {
+    if let Some(id) = worker::worker_id() {
+        worker::run_worker(&id);
+        return;
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let history = match History::load(history::history_path()) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", HISTORY_FILE, e);
+            process::exit(1);
+        }
+    };
+
+    let readme_path = advent_of_code::root_dir().join("README.md");
+    let contents = match fs::read_to_string(&readme_path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", readme_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    let listed = readme::parse_stars(&contents, args.year);
+    let options = WorkerOptions {
+        timeout: worker::DEFAULT_TIMEOUT,
+        args: vec![],
+    };
+    let statuses: Vec<DayStatus> = (1..=25)
+        .filter_map(|day| {
+            let stars = listed.iter().find(|(d, _)| *d == day).map(|(_, s)| *s);
+            day_status(args.year, day, &history, stars, &options)
+        })
+        .collect();
+    let table = readme::render_table(args.year, &statuses);
+
+    if args.dry_run {
+        println!("{}", table);
+        return;
+    }
+
+    let updated = readme::replace_table(&contents, &table)
+        .ok_or_else(|| format!("missing \"{}\" markers", readme::README_MARKER))
+        .and_then(|updated| fs::write(&readme_path, updated).map_err(|e| e.to_string()));
+
+    if let Err(e) = updated {
+        eprintln!("Failed to update \"{}\": {}", readme_path.display(), e);
+        process::exit(1);
+    }
+
+    let stars: usize = statuses
+        .iter()
+        .map(|status| status.stars.iter().filter(|&&star| star).count())
+        .sum();
+    println!(
+        "🎄 Updated the {} results in \"README.md\": {} ⭐",
+        args.year, stars
+    );
+}
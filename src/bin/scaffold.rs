@@ -2,113 +2,342 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::helpers::regex;
+use advent_of_code::scaffold;
+use advent_of_code::template::{self, Placeholders};
+use advent_of_code::DEFAULT_YEAR;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const DAYS_PATH: &str = "src/days.rs";
+const README_PATH: &str = "README.md";
+const LAUNCH_PATH: &str = ".vscode/launch.json";
+
+struct Args {
+    day: u8,
+    year: u16,
+    template: String,
+    part_one_type: String,
+    part_two_type: String,
+    dry_run: bool,
+    undo: bool,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let undo: Option<u8> = args.opt_value_from_str("--undo")?;
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| template::DEFAULT_TEMPLATE.to_string()),
+        part_one_type: args
+            .opt_value_from_str("--part-one-type")?
+            .unwrap_or_else(|| "u32".to_string()),
+        part_two_type: args
+            .opt_value_from_str("--part-two-type")?
+            .unwrap_or_else(|| "u32".to_string()),
+        dry_run: args.contains("--dry-run"),
+        undo: undo.is_some(),
+        day: match undo {
+            Some(day) => day,
+            None => args.free_from_str()?,
+        },
+    })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// Shows `path` relative to the project root.
+fn display(path: &Path) -> String {
+    let root = advent_of_code::root_dir();
+    path.strip_prefix(&root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+enum Change {
+    /// Creates a file that does not exist yet.
+    Create {
+        path: PathBuf,
+        contents: String,
+    },
+    Update {
+        path: PathBuf,
+        contents: String,
+        summary: String,
+    },
+    Remove {
+        path: PathBuf,
+    },
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+impl Change {
+    fn describe(&self, done: bool) -> String {
+        let verb = |planned: &str, past: &str| if done { past } else { planned }.to_string();
+        match self {
+            Change::Create { path, contents } if contents.is_empty() => {
+                format!(
+                    "{} empty file \"{}\"",
+                    verb("create", "Created"),
+                    display(path)
+                )
+            }
+            Change::Create { path, .. } => {
+                format!("{} \"{}\"", verb("create", "Created"), display(path))
+            }
+            Change::Update { path, summary, .. } => {
+                format!(
+                    "{} \"{}\": {}",
+                    verb("update", "Updated"),
+                    display(path),
+                    summary
+                )
+            }
+            Change::Remove { path } => {
+                format!("{} \"{}\"", verb("remove", "Removed"), display(path))
+            }
+        }
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    fn apply(&self) -> Result<(), std::io::Error> {
+        match self {
+            Change::Create { path, contents } => path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| OpenOptions::new().write(true).create_new(true).open(path))
+                .and_then(|mut file| file.write_all(contents.as_bytes())),
+            Change::Update { path, contents, .. } => fs::write(path, contents),
+            Change::Remove { path } => fs::remove_file(path).map(|_| {
+                // clean up the year's folders if this was their last file.
+                for dir in path.ancestors().skip(1) {
+                    if fs::remove_dir(dir).is_err() {
+                        break;
+                    }
+                }
+            }),
+        }
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Plans an update of `path` if it exists and `edit` changes it.
+fn plan_update(
+    changes: &mut Vec<Change>,
+    path: &str,
+    summary: String,
+    edit: impl Fn(&str) -> Option<String>,
+) {
+    let path = advent_of_code::root_dir().join(path);
+    if let Some(contents) = fs::read_to_string(&path).ok().and_then(|c| edit(&c)) {
+        changes.push(Change::Update {
+            path,
+            contents,
+            summary,
+        });
+    }
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn render_module(args: &Args, template_name: &str, types: (String, String)) -> String {
+    let module = template::load(template_name).and_then(|template| {
+        template::render(
+            &template,
+            &Placeholders {
+                day: args.day,
+                year: args.year,
+                part_one_type: types.0,
+                part_two_type: types.1,
+            },
+        )
+    });
+    match module {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to render template \"{}\": {}", template_name, e);
+            process::exit(1);
+        }
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
-}
+fn plan_scaffold(args: &Args, paths: &DayPaths) -> Vec<Change> {
+    let mut changes = vec![];
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
+    if paths.module.exists() {
+        println!(
+            "Module file \"{}\" already exists, keeping it.",
+            display(&paths.module)
+        );
+    } else {
+        let types = (args.part_one_type.clone(), args.part_two_type.clone());
+        changes.push(Change::Create {
+            path: paths.module.clone(),
+            contents: render_module(args, &args.template, types),
+        });
+    }
+
+    for path in [&paths.input, &paths.example] {
+        if !path.exists() {
+            changes.push(Change::Create {
+                path: path.clone(),
+                contents: String::new(),
+            });
         }
-    };
+    }
 
-    let day_padded = format!("{:02}", day);
+    let (year, day, bin_name) = (args.year, args.day, &paths.bin_name);
+    plan_update(
+        &mut changes,
+        DAYS_PATH,
+        format!("register day {} in `SOLUTIONS`", day),
+        |days| scaffold::register_day(days, year, day, bin_name),
+    );
+    plan_update(
+        &mut changes,
+        README_PATH,
+        format!("add day {} to the results table", day),
+        |readme| scaffold::add_readme_row(readme, year, day),
+    );
+    plan_update(
+        &mut changes,
+        LAUNCH_PATH,
+        format!("add debug configurations for day {}", day),
+        |launch| scaffold::add_launch_configurations(launch, bin_name),
+    );
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    changes
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
+/// A module is unedited if it is still identical to one of the templates.
+fn is_unedited(args: &Args, module: &str) -> bool {
+    let (mut part_one, mut part_two) = (None, None);
+    for caps in
+        regex!(r"fn (part_one|part_two)\(_?input: &str\) -> Option<(.+)> \{").captures_iter(module)
+    {
+        let return_type = Some(caps[2].to_string());
+        match &caps[1] {
+            "part_one" => part_one = part_one.or(return_type),
+            _ => part_two = part_two.or(return_type),
         }
+    }
+    let types = match (part_one, part_two) {
+        (Some(part_one), Some(part_two)) => (part_one, part_two),
+        _ => return false,
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+    template::available()
+        .iter()
+        .any(|name| render_module(args, name, types.clone()) == module)
+}
+
+fn plan_undo(args: &Args, paths: &DayPaths) -> Vec<Change> {
+    let mut changes = vec![];
+
+    if let Ok(module) = fs::read_to_string(&paths.module) {
+        if !is_unedited(args, &module) {
+            eprintln!(
+                "\"{}\" has been edited since it was scaffolded, refusing to remove it.",
+                display(&paths.module)
+            );
             process::exit(1);
         }
+        changes.push(Change::Remove {
+            path: paths.module.clone(),
+        });
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    for path in [&paths.input, &paths.example] {
+        match fs::metadata(path) {
+            Ok(metadata) if metadata.len() == 0 => {
+                changes.push(Change::Remove { path: path.clone() })
+            }
+            Ok(_) => println!("\"{}\" is not empty, keeping it.", display(path)),
+            Err(_) => {}
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+    }
+
+    let (year, day, bin_name) = (args.year, args.day, &paths.bin_name);
+    plan_update(
+        &mut changes,
+        DAYS_PATH,
+        format!("unregister day {}", day),
+        |days| scaffold::unregister_day(days, year, day, bin_name),
+    );
+    plan_update(
+        &mut changes,
+        README_PATH,
+        format!("remove day {} from the results table", day),
+        |readme| scaffold::remove_readme_row(readme, year, day),
+    );
+    plan_update(
+        &mut changes,
+        LAUNCH_PATH,
+        format!("remove the debug configurations of day {}", day),
+        |launch| scaffold::remove_launch_configurations(launch, bin_name),
+    );
+
+    changes
+}
+
+struct DayPaths {
+    bin_name: String,
+    module: PathBuf,
+    input: PathBuf,
+    example: PathBuf,
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
+    };
+
+    let paths = DayPaths {
+        bin_name: advent_of_code::bin_name(args.year, args.day),
+        module: advent_of_code::module_path(args.year, args.day),
+        input: advent_of_code::input_path("inputs", args.year, args.day),
+        example: advent_of_code::input_path("examples", args.year, args.day),
+    };
+
+    let changes = if args.undo {
+        plan_undo(&args, &paths)
+    } else {
+        plan_scaffold(&args, &paths)
+    };
+
+    if changes.is_empty() {
+        println!("Nothing to do for day {}.", args.day);
+        return;
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    if args.dry_run {
+        for change in &changes {
+            println!("Would {}", change.describe(false));
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+        return;
+    }
+
+    for change in &changes {
+        match change.apply() {
+            Ok(_) => println!("{}", change.describe(true)),
+            Err(e) => {
+                eprintln!("Failed to {}: {}", change.describe(false), e);
+                process::exit(1);
+            }
         }
     }
 
-    println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if !args.undo {
+        println!("---");
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            &paths.bin_name
+        );
+    }
 }
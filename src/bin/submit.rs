@@ -0,0 +1,152 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers;
+use advent_of_code::client::Client;
+use advent_of_code::submit::{self, Outcome, Submissions, SUBMISSIONS_FILE};
+use advent_of_code::{days, SolverOutput, DEFAULT_YEAR};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: Option<u16>,
+    base_url: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        part: args.value_from_str("--part")?,
+        answer: args.opt_value_from_str("--answer")?,
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        base_url: args.opt_value_from_str("--base-url")?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// Runs the registered solver of `day` on the puzzle input.
+fn solve(year: u16, day: u8, part: u8) -> String {
+    let solution = days::SOLUTIONS
+        .iter()
+        .find(|s| s.year == year && s.day == day)
+        .unwrap_or_else(|| {
+            exit_with_error(format!(
+                "Day {} of {} is not registered in \"src/days.rs\". Pass an answer with `--answer`.",
+                day, year
+            ))
+        });
+
+    let input =
+        advent_of_code::try_read_file("inputs", year, day).unwrap_or_else(|e| exit_with_error(e));
+    let solver = if part == 1 {
+        solution.part_one
+    } else {
+        solution.part_two
+    };
+
+    match solver(&input) {
+        SolverOutput::Solved(answer) => answer,
+        SolverOutput::NotSolved => exit_with_error(format!("Part {} is not solved yet.", part)),
+        SolverOutput::Failed(error) => exit_with_error(format!("Part {} failed: {}", part, error)),
+    }
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|e| {
+        exit_with_error(format!(
+            "Failed to process arguments: {}. example: `cargo submit 7 --part 1`",
+            e
+        ))
+    });
+
+    if !(1..=2).contains(&args.part) {
+        exit_with_error(format!("Invalid part {}, expected 1 or 2.", args.part));
+    }
+
+    let year = args.year.unwrap_or(DEFAULT_YEAR);
+    let answer = args
+        .answer
+        .unwrap_or_else(|| solve(year, args.day, args.part));
+    let answer = answer.trim();
+
+    let mut submissions = Submissions::load(submit::submissions_path()).unwrap_or_else(|e| {
+        exit_with_error(format!("Failed to read \"{}\": {}", SUBMISSIONS_FILE, e))
+    });
+
+    if let Some(wait) = submissions.remaining_wait() {
+        exit_with_error(format!(
+            "⏳ Submitted too recently, please wait another {}s.",
+            wait.as_secs()
+        ));
+    }
+
+    if let Some(previous) = submissions.rejection(year, args.day, args.part, answer) {
+        let reason = match previous.hint {
+            Some(hint) => format!("{} was {}", previous.answer, hint),
+            None => format!("{} was wrong", previous.answer),
+        };
+        exit_with_error(format!("❌ Not submitting {}: {}.", answer, reason));
+    }
+
+    let known = answers::read_answers(year, args.day)[usize::from(args.part) - 1].clone();
+    if known.as_deref() == Some(answer) {
+        println!("✅ {} is already known to be correct.", answer);
+        return;
+    }
+
+    let client = Client::from_env(args.base_url).unwrap_or_else(|e| exit_with_error(e));
+    println!(
+        "Submitting {} for day {} part {} ({})...",
+        answer, args.day, args.part, year
+    );
+
+    let response = client
+        .post_answer(year, args.day, args.part, answer)
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to submit answer: {}", e)));
+    let outcome = submit::parse_response(&response);
+
+    submissions.record(year, args.day, args.part, answer, &outcome);
+    if let Err(e) = submissions.save(submit::submissions_path()) {
+        eprintln!("Failed to write \"{}\": {}", SUBMISSIONS_FILE, e);
+    }
+
+    match outcome {
+        Outcome::Correct => {
+            println!("🎄 That's the right answer!");
+            match answers::write_answer(year, args.day, args.part, answer) {
+                Ok(_) => println!(
+                    "Stored it in \"{}\".",
+                    advent_of_code::input_path("answers", year, args.day).display()
+                ),
+                Err(e) => eprintln!("Failed to store answer: {}", e),
+            }
+        }
+        Outcome::Incorrect { hint, wait } => {
+            let hint = hint.map_or(String::new(), |hint| format!(", it is {}", hint));
+            let wait = wait.map_or(String::new(), |wait| {
+                format!(" Wait {}s before trying again.", wait.as_secs())
+            });
+            exit_with_error(format!("❌ That's not the right answer{}.{}", hint, wait));
+        }
+        Outcome::RateLimited { wait } => {
+            exit_with_error(format!(
+                "⏳ Submitted too recently, please wait another {}s.",
+                wait.as_secs()
+            ));
+        }
+        Outcome::WrongLevel => {
+            exit_with_error("This part is either solved already or not unlocked yet.");
+        }
+        Outcome::Unknown(text) => {
+            exit_with_error(format!("Could not understand the response: {}", text));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    path::PathBuf,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/strobelm/AdventOfCode2022";
+
+/// Why a request to the Advent of Code website failed.
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session cookie in `AOC_SESSION` or the session file.
+    MissingSession { session_file: Option<PathBuf> },
+    /// The server answered with an error status.
+    Status { status: u16, body: String },
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession { session_file } => {
+                write!(f, "no session cookie found. Set `AOC_SESSION`")?;
+                match session_file {
+                    Some(path) => write!(f, " or write it to \"{}\".", path.display()),
+                    None => write!(f, "."),
+                }
+            }
+            ClientError::Status { status: 404, .. } => {
+                write!(f, "not found (404). Is the puzzle unlocked yet?")
+            }
+            ClientError::Status { status: 400, .. } => {
+                write!(
+                    f,
+                    "bad request (400). Your session cookie is probably expired."
+                )
+            }
+            ClientError::Status { status, body } => {
+                write!(f, "request failed with status {}: {}", status, body.trim())
+            }
+            ClientError::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+/// The file the session cookie is read from if `AOC_SESSION` is not set.
+/// Shared with aoc-cli, so existing setups keep working.
+pub fn session_file() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".adventofcode.session"))
+}
+
+/// Reads the session cookie from `AOC_SESSION` or [`session_file`].
+pub fn read_session() -> Result<String, ClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let session_file = session_file();
+    session_file
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(ClientError::MissingSession { session_file })
+}
+
+/// A minimal client for the Advent of Code website.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client from `AOC_SESSION` or the session file.
+    /// Talks to `base_url` if given, else to `AOC_BASE_URL` or the real website.
+    pub fn from_env(base_url: Option<String>) -> Result<Self, ClientError> {
+        let base_url = base_url
+            .or_else(|| env::var("AOC_BASE_URL").ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.into());
+        Ok(Client::new(&base_url, &read_session()?))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn url(&self, year: u16, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn read_response(result: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+        match result {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| ClientError::Transport(e.to_string())),
+            Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(e) => Err(ClientError::Transport(e.to_string())),
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8, path: &str) -> Result<String, ClientError> {
+        let request = self
+            .agent
+            .get(&self.url(year, day, path))
+            .set("Cookie", &self.cookie());
+        Client::read_response(request.call())
+    }
+
+    /// Submits `answer` for `part` of `day` and returns the response page.
+    pub fn post_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let request = self
+            .agent
+            .post(&self.url(year, day, "/answer"))
+            .set("Cookie", &self.cookie());
+        let level = part.to_string();
+        Client::read_response(request.send_form(&[("level", &level), ("answer", answer)]))
+    }
+
+    /// Fetches the puzzle input of `day`.
+    pub fn get_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(year, day, "/input")
+    }
+}
+
+/// A local stand-in for the Advent of Code website that answers a single request.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves `body` with `status` once. The handle returns the raw request that was received.
+    pub fn serve_once(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {} MOCK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_input() {
+        let (base_url, server) = mock::serve_once(200, "1000\n2000\n");
+        let client = Client::new(&base_url, "abc123");
+
+        assert_eq!(client.get_input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn test_get_input_not_unlocked() {
+        let (base_url, server) = mock::serve_once(404, "404 Not Found");
+        let client = Client::new(&base_url, "abc123");
+
+        let err = client.get_input(2022, 25).unwrap_err();
+        assert!(matches!(err, ClientError::Status { status: 404, .. }));
+        server.join().unwrap();
+    }
+}
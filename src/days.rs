@@ -0,0 +1,56 @@
+/*
+ * Registry of all solved days, used by the `all` runner to call the solvers in-process.
+ * The day modules are the binaries in `./bin`, compiled into the library a second time.
+ */
+#![allow(dead_code)]
+
+use crate::{solution, Solution};
+
+#[path = "bin/01.rs"]
+mod day01;
+#[path = "bin/02.rs"]
+mod day02;
+#[path = "bin/03.rs"]
+mod day03;
+#[path = "bin/04.rs"]
+mod day04;
+#[path = "bin/05.rs"]
+mod day05;
+#[path = "bin/06.rs"]
+mod day06;
+#[path = "bin/07.rs"]
+mod day07;
+#[path = "bin/08.rs"]
+mod day08;
+#[path = "bin/09.rs"]
+mod day09;
+#[path = "bin/10.rs"]
+mod day10;
+#[path = "bin/11.rs"]
+mod day11;
+#[path = "bin/12.rs"]
+mod day12;
+#[path = "bin/13.rs"]
+mod day13;
+#[path = "bin/14.rs"]
+mod day14;
+#[path = "bin/15.rs"]
+mod day15;
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(2022, 1, day01),
+    solution!(2022, 2, day02),
+    solution!(2022, 3, day03),
+    solution!(2022, 4, day04),
+    solution!(2022, 5, day05),
+    solution!(2022, 6, day06),
+    solution!(2022, 7, day07),
+    solution!(2022, 8, day08),
+    solution!(2022, 9, day09),
+    solution!(2022, 10, day10),
+    solution!(2022, 11, day11),
+    solution!(2022, 12, day12),
+    solution!(2022, 13, day13),
+    solution!(2022, 14, day14),
+    solution!(2022, 15, day15),
+];
@@ -0,0 +1,25 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Lists the example files of a day. `build.rs` includes this file with `#[path]`,
+//! so it can only depend on `std`.
+
+use std::{fs, path::Path};
+
+/// The names of the `.txt` files in `dir` without their extension, sorted. Empty if `dir` doesn't exist.
+pub fn list(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
@@ -0,0 +1,215 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{IntoSolverOutput, SolverOutput};
+use std::fmt::{self, Display};
+use std::{fs, path::PathBuf};
+
+#[path = "example_names.rs"]
+mod names;
+
+/// An example input from `src/<year>/examples/NN/<name>.txt` together with its expected answers.
+///
+/// Expected answers are given as front matter at the start of the file:
+///
+/// ```text
+/// ---
+/// part_one: 13
+/// part_two:
+/// ##..
+/// #..#
+/// ---
+/// R 4
+/// U 4
+/// ```
+///
+/// Multi-line answers start on the line after their key. Parts without an expected answer are not checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Example {
+    pub fn parse(contents: &str) -> Example {
+        let contents = contents.replace("\r\n", "\n");
+        let front_matter = contents
+            .strip_prefix("---\n")
+            .and_then(|rest| rest.split_once("\n---\n"));
+
+        let (front_matter, input) = match front_matter {
+            Some((front_matter, input)) => (front_matter, input),
+            None => {
+                return Example {
+                    input: contents,
+                    part_one: None,
+                    part_two: None,
+                }
+            }
+        };
+
+        let mut example = Example {
+            input: input.to_string(),
+            part_one: None,
+            part_two: None,
+        };
+
+        let mut current = None;
+        for line in front_matter.lines() {
+            let value = if let Some(value) = line.strip_prefix("part_one:") {
+                current = Some(&mut example.part_one);
+                value
+            } else if let Some(value) = line.strip_prefix("part_two:") {
+                current = Some(&mut example.part_two);
+                value
+            } else {
+                line
+            };
+
+            if let Some(answer) = current.as_mut() {
+                let value = value.trim();
+                match answer {
+                    Some(answer) if !value.is_empty() => {
+                        answer.push('\n');
+                        answer.push_str(value);
+                    }
+                    Some(_) => {}
+                    None if !value.is_empty() => **answer = Some(value.to_string()),
+                    None => {}
+                }
+            }
+        }
+
+        example
+    }
+}
+
+/// Writes the example back in the format read by [`Example::parse`].
+impl Display for Example {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.part_one.is_some() || self.part_two.is_some() {
+            writeln!(f, "---")?;
+            for (key, answer) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+                match answer {
+                    Some(answer) if answer.contains('\n') => writeln!(f, "{}:\n{}", key, answer)?,
+                    Some(answer) => writeln!(f, "{}: {}", key, answer)?,
+                    None => {}
+                }
+            }
+            writeln!(f, "---")?;
+        }
+        f.write_str(&self.input)
+    }
+}
+
+fn examples_dir(year: u16, day: u8) -> PathBuf {
+    crate::year_dir(year)
+        .join("examples")
+        .join(format!("{:02}", day))
+}
+
+/// `src/<year>/examples/NN/<name>.txt`.
+pub fn example_path(year: u16, day: u8, name: &str) -> PathBuf {
+    examples_dir(year, day).join(format!("{}.txt", name))
+}
+
+/// The names of the examples in `src/<year>/examples/NN/`, sorted.
+pub fn example_names(year: u16, day: u8) -> Vec<String> {
+    names::list(&examples_dir(year, day))
+}
+
+/// Reads `src/<year>/examples/NN/<name>.txt`.
+pub fn read_example(year: u16, day: u8, name: &str) -> Example {
+    let path = example_path(year, day, name);
+
+    let contents = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read \"{}\": {}", path.display(), e));
+    Example::parse(&contents)
+}
+
+/// Runs both parts on an example and asserts the answers given in its front matter.
+/// Called by the tests generated with [`example_tests!`](crate::example_tests).
+pub fn check<A: IntoSolverOutput, B: IntoSolverOutput>(
+    year: u16,
+    day: u8,
+    name: &str,
+    part_one: impl Fn(&str) -> A,
+    part_two: impl Fn(&str) -> B,
+) {
+    let example = read_example(year, day, name);
+    assert!(
+        example.part_one.is_some() || example.part_two.is_some(),
+        "example \"{}\" of day {} has no expected answers in its front matter",
+        name,
+        day
+    );
+
+    if let Some(expected) = &example.part_one {
+        assert_eq!(
+            part_one(&example.input).into_solver_output(),
+            SolverOutput::Solved(expected.clone()),
+            "part one of example \"{}\"",
+            name
+        );
+    }
+
+    if let Some(expected) = &example.part_two {
+        assert_eq!(
+            part_two(&example.input).into_solver_output(),
+            SolverOutput::Solved(expected.clone()),
+            "part two of example \"{}\"",
+            name
+        );
+    }
+}
+
+/// Generates a test for every example file in `src/<year>/examples/NN/`.
+/// Invoke it inside a day's test module, which needs `part_one` and `part_two` in scope.
+#[macro_export]
+macro_rules! example_tests {
+    ($year:literal, $day:literal) => {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/examples/",
+            stringify!($year),
+            "-",
+            stringify!($day),
+            ".rs"
+        ));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_front_matter() {
+        let example = Example::parse("---\npart_one: 13\npart_two:\n##.\n#.#\n---\nR 4\nU 4\n");
+        assert_eq!(
+            example,
+            Example {
+                input: "R 4\nU 4\n".into(),
+                part_one: Some("13".into()),
+                part_two: Some("##.\n#.#".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_without_front_matter() {
+        let example = Example::parse("R 4\nU 4\n");
+        assert_eq!(example.input, "R 4\nU 4\n");
+        assert_eq!(example.part_one, None);
+        assert_eq!(example.part_two, None);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let contents = "---\npart_one: 13\npart_two:\n##.\n#.#\n---\nR 4\nU 4\n";
+        assert_eq!(Example::parse(contents).to_string(), contents);
+        assert_eq!(Example::parse("R 4\n").to_string(), "R 4\n");
+    }
+}
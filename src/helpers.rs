@@ -2,3 +2,13 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+mod grid;
+pub mod parse;
+mod point;
+mod regex;
+
+pub use grid::{Grid, RaggedRowError};
+pub use point::{Coordinate, Point, Point3};
+
+pub use crate::regex;
+pub use ::regex::Regex;
@@ -0,0 +1,310 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Offsets of the orthogonal neighbours: up, right, down, left.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours, clockwise starting at the top.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid stored row by row. Cells are addressed as `(x, y)`, with `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A line of the input was longer or shorter than the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedRowError {
+    /// 1-based line number.
+    pub line: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for RaggedRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {} has {} cells, expected {} like the first line",
+            self.line, self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for RaggedRowError {}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one row per line, converting every character with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, RaggedRowError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (index, line) in input.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let found = cells.len() - before;
+
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(RaggedRowError {
+                        line: index + 1,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at `(x, y)`, or `None` if it is out of bounds.
+    /// Accepts signed coordinates, so `grid.get(x - 1, y)` is safe at the left edge.
+    pub fn get<I: TryInto<usize>>(&self, x: I, y: I) -> Option<&T> {
+        let index = self.index_of(x.try_into().ok()?, y.try_into().ok()?)?;
+        Some(&self.cells[index])
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, x: I, y: I) -> Option<&mut T> {
+        let index = self.index_of(x.try_into().ok()?, y.try_into().ok()?)?;
+        Some(&mut self.cells[index])
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The cells of column `x` from top to bottom, without copying them.
+    pub fn column(
+        &self,
+        x: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone + '_ {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// All cells with their coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// The coordinates of the first cell, row by row, that satisfies `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let index = self.cells.iter().position(predicate)?;
+        Some((index % self.width, index / self.width))
+    }
+
+    fn offsets(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx)?;
+            let y = y.checked_add_signed(*dy)?;
+            self.index_of(x, y).map(|_| (x, y))
+        })
+    }
+
+    /// The coordinates of the up to 4 orthogonal neighbours of `(x, y)` that are inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets((x, y), &NEIGHBOURS_4)
+    }
+
+    /// The coordinates of the up to 8 orthogonal and diagonal neighbours of `(x, y)` that are inside the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets((x, y), &NEIGHBOURS_8)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is out of bounds of a {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is out of bounds of a {}x{} grid",
+                x, y, width, height
+            )
+        })
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = RaggedRowError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Grid::parse(input, |c| c)
+    }
+}
+
+/// Renders one line per row, with the cells next to each other.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "30373\n25512\n65332\n33549\n35390\n";
+
+    fn digits() -> Grid<u32> {
+        Grid::parse(INPUT, |c| c.to_digit(10).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (5, 5));
+        assert_eq!(grid[(4, 0)], 3);
+        assert_eq!(grid[(0, 2)], 6);
+
+        assert_eq!(
+            Grid::parse("ab\nc\n", |c| c),
+            Err(RaggedRowError {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!("".parse::<Grid<char>>().unwrap().height(), 0);
+    }
+
+    #[test]
+    fn test_signed_indexing() {
+        let grid = digits();
+        assert_eq!(grid.get(1, 1), Some(&5));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(0_i64, 5), None);
+        assert_eq!(grid.get(5_usize, 0), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits();
+        assert_eq!(grid.row(1), &[2, 5, 5, 1, 2]);
+        assert_eq!(
+            grid.column(2).copied().collect::<Vec<_>>(),
+            vec![3, 5, 3, 5, 3]
+        );
+        assert_eq!(
+            grid.column(4).rev().copied().collect::<Vec<_>>(),
+            vec![0, 9, 2, 2, 3]
+        );
+        assert_eq!(grid.column(0).len(), 5);
+        assert_eq!(grid.rows().count(), 5);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits();
+        assert_eq!(
+            grid.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4(2, 2).count(), 4);
+        assert_eq!(
+            grid.neighbours8(4, 4).collect::<Vec<_>>(),
+            vec![(4, 3), (3, 4), (3, 3)]
+        );
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn test_iter_and_position() {
+        let mut grid: Grid<char> = "S.\n.E".parse().unwrap();
+        assert_eq!(grid.position(|&c| c == 'E'), Some((1, 1)));
+        assert_eq!(grid.iter().nth(2), Some(((0, 1), &'.')));
+
+        grid[(0, 1)] = '#';
+        *grid.get_mut(1, 0).unwrap() = '#';
+        assert_eq!(grid.to_string(), "S#\n#E");
+        assert_eq!(
+            grid.map(|&c| c == '#').iter().filter(|(_, &b)| b).count(),
+            2
+        );
+    }
+}
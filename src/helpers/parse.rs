@@ -0,0 +1,299 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A piece of malformed input, located by its 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A slice of the input that remembers where it starts, so errors can point at it.
+/// Every span derived from it through `lines`, `blocks`, `after`, ... keeps its location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Span<'a> {
+    /// A span covering the whole `input`.
+    pub fn new(input: &'a str) -> Self {
+        Span {
+            text: input,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// An error pointing at the start of this span.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: message.to_string(),
+        }
+    }
+
+    /// The span of `sub`, which must be a slice of this span's text.
+    fn slice(&self, sub: &'a str) -> Span<'a> {
+        let offset = sub.as_ptr() as usize - self.text.as_ptr() as usize;
+        debug_assert!(offset + sub.len() <= self.text.len());
+
+        let before = &self.text[..offset];
+        match before.rfind('\n') {
+            Some(newline) => Span {
+                text: sub,
+                line: self.line + before.matches('\n').count(),
+                column: before[newline + 1..].chars().count() + 1,
+            },
+            None => Span {
+                text: sub,
+                line: self.line,
+                column: self.column + before.chars().count(),
+            },
+        }
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.slice(self.text.trim())
+    }
+
+    /// The lines of this span, without their line endings.
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        span.text.lines().map(move |line| span.slice(line))
+    }
+
+    /// Groups of consecutive lines, separated by blank lines.
+    pub fn blocks(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        let mut lines = self.lines().peekable();
+        std::iter::from_fn(move || {
+            while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+                last = line;
+            }
+
+            let start = first.text.as_ptr() as usize - span.text.as_ptr() as usize;
+            let end = last.text.as_ptr() as usize - span.text.as_ptr() as usize + last.text.len();
+            Some(span.slice(&span.text[start..end]))
+        })
+    }
+
+    /// The rest of the line after the first occurrence of `prefix`.
+    /// Useful for labelled values like `Test: divisible by 23`.
+    pub fn after(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        let start = self
+            .text
+            .find(prefix)
+            .ok_or_else(|| self.error(format!("expected \"{}\"", prefix)))?
+            + prefix.len();
+        let rest = &self.text[start..];
+        let end = rest.find('\n').unwrap_or(rest.len());
+        Ok(self.slice(rest[..end].trim_end_matches('\r')))
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let (left, right) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected \"{}\"", separator)))?;
+        Ok((self.slice(left), self.slice(right)))
+    }
+
+    /// Parses the trimmed span with `FromStr`.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let trimmed = self.trim();
+        trimmed.text.parse().map_err(|e| {
+            trimmed.error(format!(
+                "can't parse \"{}\" as {}: {}",
+                trimmed.text,
+                short_type_name::<T>(),
+                e
+            ))
+        })
+    }
+
+    /// Every integer in the span, ignoring the text around them.
+    /// A `-` counts as a sign unless it follows a digit, so `2-4` are two numbers and `x=-2` is one.
+    pub fn numbers<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut numbers = vec![];
+        let mut chars = self.text.char_indices().peekable();
+        let mut previous = None;
+
+        while let Some((start, c)) = chars.next() {
+            let is_sign = c == '-'
+                && !previous.is_some_and(|p: char| p.is_ascii_digit())
+                && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+
+            previous = Some(c);
+            if c.is_ascii_digit() || is_sign {
+                let mut end = start + c.len_utf8();
+                while let Some((index, digit)) = chars.next_if(|(_, d)| d.is_ascii_digit()) {
+                    end = index + digit.len_utf8();
+                    previous = Some(digit);
+                }
+                numbers.push(self.slice(&self.text[start..end]).parse()?);
+            }
+        }
+
+        Ok(numbers)
+    }
+
+    /// Exactly `N` integers, as with `numbers`.
+    pub fn numbers_array<T, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let numbers = self.numbers()?;
+        let found = numbers.len();
+        numbers
+            .try_into()
+            .map_err(|_| self.error(format!("expected {} numbers, found {}", N, found)))
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// The lines of `input` as spans.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).lines()
+}
+
+/// The blocks of `input` that are separated by blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).blocks()
+}
+
+/// Parses every line of `input` with `FromStr`, stopping at the first line that fails.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    lines(input).map(|line| line.parse()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let line = Span::new("Sensor at x=-2, y=18: closest beacon is at x=10, y=-16");
+        assert_eq!(line.numbers::<i64>(), Ok(vec![-2, 18, 10, -16]));
+        assert_eq!(Span::new("2-4,6-8").numbers::<u32>(), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(
+            Span::new("move 1 from 2 to 1").numbers_array::<usize, 3>(),
+            Ok([1, 2, 1])
+        );
+        assert_eq!(
+            Span::new("move 1 from 2").numbers_array::<usize, 3>(),
+            Err(ParseError {
+                line: 1,
+                column: 1,
+                message: "expected 3 numbers, found 2".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_location() {
+        let input = "1\n2\n\nmove 3 from 99999999999 to 1\n";
+        let line = lines(input).nth(3).unwrap();
+        assert_eq!(
+            line.numbers::<u32>().unwrap_err().to_string(),
+            "line 4, column 13: can't parse \"99999999999\" as u32: number too large to fit in target type"
+        );
+
+        assert_eq!(
+            parse_lines::<u8>("1\n2\nx\n").unwrap_err(),
+            ParseError {
+                line: 3,
+                column: 1,
+                message: "can't parse \"x\" as u8: invalid digit found in string".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "Monkey 0:\n  Test: divisible by 23\n\n\nMonkey 1:\n  Test: divisible by x\n";
+        let blocks: Vec<_> = blocks(input).collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].as_str(), "Monkey 0:\n  Test: divisible by 23");
+        assert_eq!(blocks[1].line(), 5);
+        assert_eq!(blocks[1].lines().count(), 2);
+
+        assert_eq!(blocks[0].after("divisible by").unwrap().parse(), Ok(23));
+        assert_eq!(
+            blocks[1]
+                .after("divisible by ")
+                .unwrap()
+                .parse::<u64>()
+                .unwrap_err()
+                .to_string(),
+            "line 6, column 22: can't parse \"x\" as u64: invalid digit found in string"
+        );
+        assert_eq!(
+            blocks[1].after("If true:").unwrap_err().to_string(),
+            "line 5, column 1: expected \"If true:\""
+        );
+    }
+
+    #[test]
+    fn test_split_once() {
+        let line = Span::new("  Operation: new = old * 19");
+        let (operator, operand) = line.after("old ").unwrap().split_once(" ").unwrap();
+        assert_eq!((operator.as_str(), operator.column()), ("*", 24));
+        assert_eq!(operand.parse(), Ok(19));
+        assert!(operand.split_once(",").is_err());
+    }
+}
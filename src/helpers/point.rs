@@ -0,0 +1,261 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed integer that can be used as the coordinate of a [`Point`] or [`Point3`].
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const NEG_ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const NEG_ONE: Self = -1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        })*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// A point or vector in 2D. `y` grows downwards like the rows of a [`Grid`](super::Grid), so [`Point::UP`] is `(0, -1)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point or vector in 3D.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Implements the methods and operators that work the same for every dimension.
+macro_rules! impl_point {
+    ($point:ident { $($c:ident),+ }) => {
+        impl<T: Coordinate> $point<T> {
+            pub const ORIGIN: Self = $point { $($c: T::ZERO),+ };
+
+            pub const fn new($($c: T),+) -> Self {
+                $point { $($c),+ }
+            }
+
+            /// The taxicab distance: the sum of the distances along each axis.
+            pub fn manhattan(self, other: Self) -> T {
+                T::ZERO $(+ (self.$c - other.$c).abs())+
+            }
+
+            /// The chessboard distance: the largest distance along any axis.
+            pub fn chebyshev(self, other: Self) -> T {
+                T::ZERO $(.max((self.$c - other.$c).abs()))+
+            }
+
+            /// Clamps every coordinate to -1, 0 or 1.
+            pub fn signum(self) -> Self {
+                $point { $($c: self.$c.signum()),+ }
+            }
+
+            /// Moves one step towards `target`, diagonally if needed. Stays put on `target`.
+            pub fn step_towards(self, target: Self) -> Self {
+                self + (target - self).signum()
+            }
+        }
+
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl<T: Coordinate> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($c: -self.$c),+ }
+            }
+        }
+
+        /// Scales a vector.
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($c: self.$c * factor),+ }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point!(Point { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl<T: Coordinate> Point<T> {
+    pub const UP: Self = Point::new(T::ZERO, T::NEG_ONE);
+    pub const DOWN: Self = Point::new(T::ZERO, T::ONE);
+    pub const LEFT: Self = Point::new(T::NEG_ONE, T::ZERO);
+    pub const RIGHT: Self = Point::new(T::ONE, T::ZERO);
+
+    /// The 4 orthogonal directions, clockwise starting at the top.
+    pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+
+    /// The 8 orthogonal and diagonal directions, clockwise starting at the top.
+    pub const ADJACENT: [Self; 8] = [
+        Self::UP,
+        Point::new(T::ONE, T::NEG_ONE),
+        Self::RIGHT,
+        Point::new(T::ONE, T::ONE),
+        Self::DOWN,
+        Point::new(T::NEG_ONE, T::ONE),
+        Self::LEFT,
+        Point::new(T::NEG_ONE, T::NEG_ONE),
+    ];
+
+    /// Rotates by 90° clockwise around the origin, so [`Point::UP`] becomes [`Point::RIGHT`].
+    pub fn turn_right(self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90° counterclockwise around the origin, so [`Point::UP`] becomes [`Point::LEFT`].
+    pub fn turn_left(self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    /// The 6 orthogonal directions: along x, y and z, positive first.
+    pub const ORTHOGONAL: [Self; 6] = [
+        Point3::new(T::ONE, T::ZERO, T::ZERO),
+        Point3::new(T::NEG_ONE, T::ZERO, T::ZERO),
+        Point3::new(T::ZERO, T::ONE, T::ZERO),
+        Point3::new(T::ZERO, T::NEG_ONE, T::ZERO),
+        Point3::new(T::ZERO, T::ZERO, T::ONE),
+        Point3::new(T::ZERO, T::ZERO, T::NEG_ONE),
+    ];
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators() {
+        let mut p = Point::new(3, -2);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(-p, Point::new(-3, 2));
+        assert_eq!(Point::RIGHT * 5, Point::new(5, 0));
+
+        p += Point::DOWN;
+        p -= Point::LEFT;
+        assert_eq!(p, Point::new(4, -1));
+        assert_eq!(Point::from((1_i8, 2)), Point::new(1, 2));
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::from((1, 1, 1)),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let (p, q) = (Point::new(8_i64, 7), Point::new(2, 10));
+        assert_eq!(p.manhattan(q), 9);
+        assert_eq!(p.chebyshev(q), 6);
+
+        let (p, q) = (Point3::new(1, -2, 3), Point3::ORIGIN);
+        assert_eq!(p.manhattan(q), 6);
+        assert_eq!(p.chebyshev(q), 3);
+    }
+
+    #[test]
+    fn test_step_towards() {
+        let p = Point::new(0_i8, 0);
+        assert_eq!(p.step_towards(Point::new(2, -5)), Point::new(1, -1));
+        assert_eq!(p.step_towards(Point::new(0, 3)), Point::new(0, 1));
+        assert_eq!(p.step_towards(p), p);
+        assert_eq!(
+            Point3::new(1, 1, 1).step_towards(Point3::new(1, 4, -4)),
+            Point3::new(1, 2, 0)
+        );
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Point::<i32>::UP.turn_right(), Point::RIGHT);
+        assert_eq!(Point::<i32>::UP.turn_left(), Point::LEFT);
+        for (i, &direction) in Point::<i32>::ORTHOGONAL.iter().enumerate() {
+            assert_eq!(direction.turn_right(), Point::ORTHOGONAL[(i + 1) % 4]);
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+        assert_eq!(Point::new(2, 1).turn_right(), Point::new(-1, 2));
+        assert!(Point::<i32>::ADJACENT
+            .iter()
+            .all(|d| d.chebyshev(Point::ORIGIN) == 1));
+        assert!(Point3::<i32>::ORTHOGONAL
+            .iter()
+            .all(|d| d.manhattan(Point3::ORIGIN) == 1));
+    }
+}
@@ -0,0 +1,36 @@
+/// Compiles a regex literal once and returns a `&'static Regex` on every later call,
+/// so it can be used inside loops and hot functions without recompiling it.
+///
+/// ```
+/// use advent_of_code::helpers::regex;
+///
+/// let caps = regex!(r"(\d+),(\d+)").captures("498,4").unwrap();
+/// assert_eq!(&caps[2], "4");
+/// ```
+#[macro_export]
+macro_rules! regex {
+    ($re:literal $(,)?) => {{
+        static RE: std::sync::OnceLock<$crate::helpers::Regex> = std::sync::OnceLock::new();
+        // only runs on the first call, even when the macro is used inside a loop.
+        #[allow(clippy::regex_creation_in_loops)]
+        fn compile() -> $crate::helpers::Regex {
+            $crate::helpers::Regex::new($re).unwrap()
+        }
+        RE.get_or_init(compile)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::Regex;
+
+    fn digits() -> &'static Regex {
+        regex!(r"\d+")
+    }
+
+    #[test]
+    fn test_regex_is_compiled_once() {
+        assert!(std::ptr::eq(digits(), digits()));
+        assert_eq!(digits().find_iter("move 1 from 22").count(), 2);
+    }
+}
@@ -0,0 +1,221 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::PartResult;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// File name of the benchmark history. Always read from the project root, see [`history_path`].
+pub const HISTORY_FILE: &str = "bench_history.json";
+
+/// Where `cargo all --save` records timings, regardless of the directory cargo was started from.
+pub fn history_path() -> PathBuf {
+    crate::root_dir().join(HISTORY_FILE)
+}
+
+/// A recorded timing of one part at one git revision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub revision: String,
+    /// Missing in entries recorded before multi-year support.
+    #[serde(default = "legacy_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed_ns: u64,
+    /// Seconds since the unix epoch.
+    pub recorded_at: u64,
+}
+
+fn legacy_year() -> u16 {
+    crate::LEGACY_YEAR
+}
+
+/// How a part performed compared to the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change in percent, positive if the part got slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Timings of previous runs, keyed by year, day, part and git revision.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Loads the history at `path`, starting a new one if the file does not exist yet.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, contents + "\n")
+    }
+
+    /// Records solved parts of `results` for `revision`, replacing earlier timings of the same revision.
+    pub fn record(&mut self, revision: &str, results: &[PartResult]) {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        for result in results.iter().filter(|r| r.answer.is_some()) {
+            self.entries.retain(|e| {
+                !(e.revision == revision
+                    && e.year == result.year
+                    && e.day == result.day
+                    && e.part == result.part)
+            });
+            self.entries.push(HistoryEntry {
+                revision: revision.to_string(),
+                year: result.year,
+                day: result.day,
+                part: result.part,
+                elapsed_ns: result.elapsed_ns,
+                recorded_at,
+            });
+        }
+    }
+
+    /// The revision that was recorded last.
+    pub fn latest_revision(&self) -> Option<&str> {
+        self.entries.last().map(|e| e.revision.as_str())
+    }
+
+    pub fn get(&self, revision: &str, year: u16, day: u8, part: u8) -> Option<&HistoryEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.revision == revision && e.year == year && e.day == day && e.part == part)
+    }
+
+    /// The most recently recorded timing of a part, regardless of the revision.
+    pub fn latest(&self, year: u16, day: u8, part: u8) -> Option<&HistoryEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|e| e.year == year && e.day == day && e.part == part)
+    }
+
+    /// Compares solved parts of `results` against `baseline`.
+    /// A part regressed if it got slower by more than `threshold` percent.
+    pub fn compare(
+        &self,
+        baseline: &str,
+        results: &[PartResult],
+        threshold: f64,
+    ) -> Vec<Comparison> {
+        results
+            .iter()
+            .filter(|r| r.answer.is_some())
+            .filter_map(|r| {
+                let entry = self.get(baseline, r.year, r.day, r.part)?;
+                let change = if entry.elapsed_ns == 0 {
+                    0_f64
+                } else {
+                    (r.elapsed_ns as f64 / entry.elapsed_ns as f64 - 1_f64) * 100_f64
+                };
+                Some(Comparison {
+                    year: r.year,
+                    day: r.day,
+                    part: r.part,
+                    baseline: Duration::from_nanos(entry.elapsed_ns),
+                    current: r.elapsed(),
+                    change,
+                    regressed: change > threshold,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the short hash of `HEAD`, suffixed with `-dirty` if there are uncommitted changes.
+pub fn git_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let revision = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    let is_dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|o| !o.stdout.is_empty());
+
+    Some(if is_dirty {
+        format!("{}-dirty", revision)
+    } else {
+        revision
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: u8, elapsed_ns: u64) -> PartResult {
+        PartResult::new(2022, day, part, Some(0), Duration::from_nanos(elapsed_ns))
+    }
+
+    #[test]
+    fn test_record_replaces_same_revision() {
+        let mut history = History::default();
+        history.record("abc", &[result(1, 1, 100), result(1, 2, 200)]);
+        history.record("abc", &[result(1, 1, 150)]);
+        history.record("def", &[result(1, 1, 90)]);
+
+        assert_eq!(history.get("abc", 2022, 1, 1).unwrap().elapsed_ns, 150);
+        assert_eq!(history.get("abc", 2022, 1, 2).unwrap().elapsed_ns, 200);
+        assert_eq!(history.get("def", 2022, 1, 1).unwrap().elapsed_ns, 90);
+        assert_eq!(history.latest_revision(), Some("def"));
+        assert_eq!(history.latest(2022, 1, 1).unwrap().revision, "def");
+        assert_eq!(history.latest(2022, 1, 2).unwrap().revision, "abc");
+    }
+
+    #[test]
+    fn test_compare_flags_regressions() {
+        let mut history = History::default();
+        history.record("abc", &[result(1, 1, 100), result(1, 2, 100)]);
+
+        let comparisons = history.compare(
+            "abc",
+            &[result(1, 1, 105), result(1, 2, 130), result(2, 1, 10)],
+            10_f64,
+        );
+
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert!((comparisons[1].change - 30_f64).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_load_entries_without_year() {
+        let history: History = serde_json::from_str(
+            r#"{"entries":[{"revision":"abc","day":1,"part":1,"elapsed_ns":100,"recorded_at":0}]}"#,
+        )
+        .unwrap();
+        assert_eq!(history.get("abc", 2022, 1, 1).unwrap().elapsed_ns, 100);
+    }
+}
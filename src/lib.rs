@@ -3,92 +3,637 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use answers::Verdict;
+use bench::{BenchConfig, BenchStats};
+use serde::{Deserialize, Serialize};
 use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
 use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
 
+// lets the day modules compiled into `days` keep referring to `advent_of_code::`.
+extern crate self as advent_of_code;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod examples;
 pub mod helpers;
+pub mod history;
+pub mod manifest;
+pub mod puzzle;
+pub mod readme;
+pub mod scaffold;
+pub mod submit;
+pub mod template;
+pub mod worker;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+
+/// What a solver returned, with its answer or error converted to a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolverOutput {
+    Solved(String),
+    /// The solver returned `None`, i.e. the part is not implemented yet.
+    NotSolved,
+    /// The solver returned an error for this input.
+    Failed(String),
+}
+
+/// Return types accepted from `part_one` and `part_two`: `Option<T>`, or `Result<T, E>` to report why the solver failed.
+pub trait IntoSolverOutput {
+    fn into_solver_output(self) -> SolverOutput;
+}
+
+impl IntoSolverOutput for SolverOutput {
+    fn into_solver_output(self) -> SolverOutput {
+        self
+    }
+}
+
+impl<T: Display> IntoSolverOutput for Option<T> {
+    fn into_solver_output(self) -> SolverOutput {
+        match self {
+            Some(answer) => SolverOutput::Solved(answer.to_string()),
+            None => SolverOutput::NotSolved,
+        }
+    }
+}
+
+impl<T: Display, E: Display> IntoSolverOutput for Result<T, E> {
+    fn into_solver_output(self) -> SolverOutput {
+        match self {
+            Ok(answer) => SolverOutput::Solved(answer.to_string()),
+            Err(e) => SolverOutput::Failed(e.to_string()),
+        }
+    }
+}
+
+/// An error that is displayed followed by its chain of [`Error::source`]s, separated by `: `.
+/// Return `Result<T, ErrorChain>` from a solver to see why its errors happened; `?` converts any error.
+#[derive(Debug)]
+pub struct ErrorChain(Box<dyn Error>);
+
+impl<E: Error + 'static> From<E> for ErrorChain {
+    fn from(error: E) -> Self {
+        ErrorChain(Box::new(error))
+    }
+}
+
+impl Display for ErrorChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut message = self.0.to_string();
+        let mut source = self.0.source();
+        while let Some(cause) = source {
+            // many errors already print their source, don't repeat it.
+            let cause_message = cause.to_string();
+            if !message.ends_with(&cause_message) {
+                message = format!("{}: {}", message, cause_message);
+            }
+            source = cause.source();
+        }
+        f.write_str(&message)
+    }
+}
+
+/// A solver for one part of a day with its outcome converted to strings, as stored in [`days::SOLUTIONS`].
+pub type Solver = fn(&str) -> SolverOutput;
+
+/// The solvers of a single day, registered in [`days::SOLUTIONS`].
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Solution {
+    /// Returns both parts of this day, paired with their part number.
+    pub fn parts(&self) -> [(u8, Solver); 2] {
+        [(1, self.part_one), (2, self.part_two)]
+    }
+}
+
+/// Creates a [`Solution`] for a day module exposing `part_one` and `part_two`.
+#[macro_export]
+macro_rules! solution {
+    ($year:expr, $day:expr, $module:path) => {{
+        use $module as day;
+        $crate::Solution {
+            year: $year,
+            day: $day,
+            part_one: |input| $crate::IntoSolverOutput::into_solver_output(day::part_one(input)),
+            part_two: |input| $crate::IntoSolverOutput::into_solver_output(day::part_two(input)),
+        }
+    }};
+}
 
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
+        if $crate::is_part_selected($part) {
+            let (year, day) = option_env!("CARGO_BIN_NAME")
+                .and_then($crate::parse_bin_name)
+                .unwrap_or(($crate::LEGACY_YEAR, 0));
+            let bench = $crate::bench::BenchConfig::from_args();
+            let mut result = $crate::run_part(year, day, $part, $solver, $input, bench.as_ref());
+            if $crate::InputSource::from_args().is_ok_and(|source| source.is_puzzle()) {
+                result = result.verified();
+            }
+            result.print($crate::OutputFormat::from_env());
+            if $crate::answers::verify_requested()
+                && result.verdict == Some($crate::answers::Verdict::Mismatch)
+            {
+                std::process::exit(1);
+            }
+        }
+    }};
+}
+
+/// The part selected with `--part 1|2`, or `None` if both parts should run.
+/// Exits with an error message if the argument is invalid.
+pub fn part_from_args() -> Option<u8> {
+    let mut args = pico_args::Arguments::from_env();
+    match args.opt_value_from_str::<_, u8>("--part") {
+        Ok(part @ (None | Some(1 | 2))) => part,
+        Ok(Some(part)) => {
+            eprintln!("Invalid part {}, expected `--part 1` or `--part 2`.", part);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Whether `part` should run, see [`part_from_args`].
+pub fn is_part_selected(part: u8) -> bool {
+    part_from_args().is_none_or(|selected| selected == part)
+}
+
+/// Runs `func` on `input` once and measures how long it took.
+pub fn run_timed<T>(func: impl FnOnce(&str) -> T, input: &str) -> (T, Duration) {
+    let timer = Instant::now();
+    let result = func(input);
+    (result, timer.elapsed())
+}
+
+/// Runs `solver` once, or benchmarks it if `bench` is given.
+pub fn run_part<T: IntoSolverOutput>(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> T,
+    input: &str,
+    bench: Option<&BenchConfig>,
+) -> PartResult {
+    match bench {
+        Some(config) => {
+            let (answer, stats) = bench::bench(solver, input, config);
+            let elapsed = Duration::from_nanos(stats.median_ns);
+            PartResult {
+                bench: Some(stats),
+                ..PartResult::new(year, day, part, answer, elapsed)
+            }
+        }
+        None => {
+            let (answer, elapsed) = run_timed(solver, input);
+            PartResult::new(year, day, part, answer, elapsed)
+        }
+    }
+}
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output with ANSI escapes.
+    Pretty,
+    /// One JSON record per line and part.
+    Json,
+}
+
+impl OutputFormat {
+    /// Selects [`OutputFormat::Json`] if `--json` was passed or `AOC_OUTPUT` is set to `json`.
+    pub fn from_env() -> Self {
+        let json_flag = env::args().skip(1).any(|arg| arg == "--json");
+        let json_var = env::var("AOC_OUTPUT").is_ok_and(|val| val.eq_ignore_ascii_case("json"));
+
+        if json_flag || json_var {
+            OutputFormat::Json
+        } else {
+            OutputFormat::Pretty
+        }
+    }
+}
+
+/// The outcome of running one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Why the solver failed, if it returned an error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Time of the single run, or the median when benchmarking.
+    pub elapsed_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+    /// Set for answers to the real input, see [`PartResult::verified`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<Verdict>,
+}
+
+impl PartResult {
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        output: impl IntoSolverOutput,
+        elapsed: Duration,
+    ) -> Self {
+        let (answer, error) = match output.into_solver_output() {
+            SolverOutput::Solved(answer) => (Some(answer), None),
+            SolverOutput::NotSolved => (None, None),
+            SolverOutput::Failed(error) => (None, Some(error)),
+        };
+        PartResult {
+            year,
+            day,
+            part,
+            answer,
+            error,
+            elapsed_ns: elapsed.as_nanos() as u64,
+            bench: None,
+            verdict: None,
+        }
+    }
+
+    /// Checks the answer against the known-good answers in `src/<year>/answers/`.
+    pub fn verified(self) -> Self {
+        let verdict = answers::verify(self.year, self.day, self.part, self.answer.as_deref());
+        PartResult {
+            verdict: Some(verdict),
+            ..self
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_ns)
+    }
+
+    fn timing(&self) -> String {
+        match &self.bench {
+            Some(stats) => format!(
+                "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, samples: {}",
+                Duration::from_nanos(stats.min_ns),
+                Duration::from_nanos(stats.median_ns),
+                Duration::from_nanos(stats.mean_ns),
+                Duration::from_nanos(stats.stddev_ns),
+                stats.samples
+            ),
+            None => format!("elapsed: {:.2?}", self.elapsed()),
+        }
+    }
+
+    pub fn print(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Pretty => {
+                println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET);
+                let mark = self
+                    .verdict
+                    .map_or(String::new(), |verdict| format!(" {}", verdict.symbol()));
+                match (&self.answer, &self.error) {
+                    (Some(answer), _) => {
+                        println!(
+                            "{}{} {}({}){}",
+                            answer,
+                            mark,
+                            ANSI_ITALIC,
+                            self.timing(),
+                            ANSI_RESET
+                        );
+                    }
+                    (None, Some(error)) => {
+                        println!("{}failed on this input:{}{}", ANSI_RED, ANSI_RESET, mark);
+                        for line in error.lines() {
+                            println!("  {}{}{}", ANSI_RED, line, ANSI_RESET);
+                        }
+                    }
+                    (None, None) => {
+                        println!("not implemented yet.{}", mark)
+                    }
                 }
-                None => {
-                    println!("not solved.")
+            }
+            OutputFormat::Json => {
+                println!("{}", serde_json::to_string(self).unwrap());
+            }
+        }
+    }
+}
+
+/// The project root that `src/<year>/<folder>/NN.txt` paths are resolved against.
+/// Defaults to the directory of this crate's `Cargo.toml` and can be overridden by setting `AOC_ROOT`.
+pub fn root_dir() -> PathBuf {
+    match env::var_os("AOC_ROOT") {
+        Some(root) if !root.is_empty() => PathBuf::from(root),
+        _ => PathBuf::from(env!("CARGO_MANIFEST_DIR")),
+    }
+}
+
+/// The year of the days written before multi-year support. Its files live directly in
+/// `src/<folder>/` and its binaries are named `NN` instead of `2022-NN`.
+pub const LEGACY_YEAR: u16 = 2022;
+
+/// The event year used if none is given.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// The directory holding the `inputs`, `examples`, `answers` and `puzzles` of `year`, i.e. `src/<year>/`.
+pub fn year_dir(year: u16) -> PathBuf {
+    let src = root_dir().join("src");
+    if year == LEGACY_YEAR {
+        src
+    } else {
+        src.join(year.to_string())
+    }
+}
+
+/// The name of the binary solving `day` of `year`, e.g. `2021-05`.
+pub fn bin_name(year: u16, day: u8) -> String {
+    if year == LEGACY_YEAR {
+        format!("{:02}", day)
+    } else {
+        format!("{}-{:02}", year, day)
+    }
+}
+
+/// Parses binary names as returned by [`bin_name`] into year and day.
+pub fn parse_bin_name(name: &str) -> Option<(u16, u8)> {
+    match name.split_once('-') {
+        Some((year, day)) => Some((year.parse().ok()?, day.parse().ok()?)),
+        None => Some((LEGACY_YEAR, name.parse().ok()?)),
+    }
+}
+
+/// The source file of the binary solving `day` of `year`.
+pub fn module_path(year: u16, day: u8) -> PathBuf {
+    root_dir()
+        .join("src")
+        .join("bin")
+        .join(format!("{}.rs", bin_name(year, day)))
+}
+
+/// The command that downloads the input of `day`, e.g. `cargo download 5 --year 2021`.
+pub fn download_command(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("cargo download {}", day)
+    } else {
+        format!("cargo download {} --year {}", day, year)
+    }
+}
+
+pub fn input_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{:02}.txt", day))
+}
+
+/// Why a file from `src/<folder>/` could not be read.
+#[derive(Debug)]
+pub enum ReadError {
+    Missing {
+        folder: String,
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    Empty {
+        folder: String,
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    NotUtf8 {
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl ReadError {
+    fn hint(folder: &str, year: u16, day: u8) -> Option<String> {
+        match folder {
+            "inputs" => Some(format!(
+                "Run `{}` to fetch your puzzle input.",
+                download_command(year, day)
+            )),
+            "examples" => Some("Paste the puzzle's example into it.".to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Missing {
+                folder,
+                year,
+                day,
+                path,
+            } => {
+                write!(f, "could not find \"{}\".", path.display())?;
+                match ReadError::hint(folder, *year, *day) {
+                    Some(hint) => write!(f, " {}", hint),
+                    None => Ok(()),
+                }
+            }
+            ReadError::Empty {
+                folder,
+                year,
+                day,
+                path,
+            } => {
+                write!(f, "\"{}\" is empty.", path.display())?;
+                match ReadError::hint(folder, *year, *day) {
+                    Some(hint) => write!(f, " {}", hint),
+                    None => Ok(()),
                 }
             }
+            ReadError::NotUtf8 { path } => {
+                write!(f, "\"{}\" does not contain valid UTF-8.", path.display())
+            }
+            ReadError::Io { path, source } => {
+                write!(f, "could not read \"{}\": {}", path.display(), source)
+            }
         }
+    }
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Reads `src/<year>/<folder>/NN.txt`, failing if it is missing, empty or not UTF-8.
+/// When `examples/NN.txt` is missing or empty, the first example in `examples/NN/` is read
+/// instead, without its front matter.
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Result<String, ReadError> {
+    let result = read_path(input_path(folder, year, day), folder, year, day);
+    match result {
+        Err(ReadError::Missing { .. } | ReadError::Empty { .. }) if folder == "examples" => {
+            match examples::example_names(year, day).first() {
+                Some(name) => read_path(examples::example_path(year, day, name), folder, year, day)
+                    .map(|contents| examples::Example::parse(&contents).input),
+                None => result,
+            }
+        }
+        result => result,
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Reads the file at `path`. `folder`, `year` and `day` are only used to give hints on errors.
+fn read_path(path: PathBuf, folder: &str, year: u16, day: u8) -> Result<String, ReadError> {
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(ReadError::Missing {
+                folder: folder.into(),
+                year,
+                day,
+                path,
+            })
+        }
+        Err(source) => return Err(ReadError::Io { path, source }),
+    };
+
+    if bytes.is_empty() {
+        return Err(ReadError::Empty {
+            folder: folder.into(),
+            year,
+            day,
+            path,
+        });
+    }
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+    String::from_utf8(bytes).map_err(|_| ReadError::NotUtf8 { path })
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    try_read_file(folder, year, day).unwrap_or_else(|e| panic!("{}", e))
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Where a day binary reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/<year>/inputs/NN.txt`, the default.
+    Puzzle,
+    /// `src/<year>/examples/NN.txt`, selected with `--example`. See [`try_read_file`] for
+    /// days whose examples live in `examples/NN/`.
+    Example,
+    /// An arbitrary file, selected with `--input <path>`.
+    Path(PathBuf),
+    /// Standard input, selected with `-` or `--input -`.
+    Stdin,
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+impl InputSource {
+    pub fn from_args() -> Result<Self, pico_args::Error> {
+        Self::parse(pico_args::Arguments::from_env())
+    }
+
+    fn parse(mut args: pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let example = args.contains("--example");
+        let path: Option<PathBuf> = args.opt_value_from_os_str("--input", |s| {
+            Ok::<_, std::convert::Infallible>(PathBuf::from(s))
+        })?;
+        // only a `-` that is not the value of `--input`, which was consumed above.
+        let stdin = args.finish().iter().any(|arg| arg == "-");
+
+        let source = match (path, stdin) {
+            (Some(path), false) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), false) => InputSource::Path(path),
+            (None, true) => InputSource::Stdin,
+            (None, false) if example => InputSource::Example,
+            (None, false) => InputSource::Puzzle,
+            (Some(_), true) => {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: "`-` and `--input` can not be combined".into(),
+                })
+            }
+        };
+
+        if example && source != InputSource::Example {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "`--example` can not be combined with another input".into(),
+            });
+        }
+
+        Ok(source)
+    }
+
+    /// Whether answers to this input can be checked against `src/<year>/answers/`.
+    pub fn is_puzzle(&self) -> bool {
+        *self == InputSource::Puzzle
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, ReadError> {
+        match self {
+            InputSource::Puzzle => try_read_file("inputs", year, day),
+            InputSource::Example => try_read_file("examples", year, day),
+            InputSource::Path(path) => read_path(path.clone(), "", year, day),
+            InputSource::Stdin => {
+                let path = PathBuf::from("-");
+                let mut input = String::new();
+                match io::stdin().read_to_string(&mut input) {
+                    Ok(_) if input.is_empty() => Err(ReadError::Empty {
+                        folder: "".into(),
+                        year,
+                        day,
+                        path,
+                    }),
+                    Ok(_) => Ok(input),
+                    Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                        Err(ReadError::NotUtf8 { path })
+                    }
+                    Err(source) => Err(ReadError::Io { path, source }),
+                }
             }
         }
-    })
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Reads the input of a day binary as selected on the command line, see [`InputSource`].
+/// Exits with an error message if the input can not be read.
+pub fn read_input(year: u16, day: u8) -> String {
+    let source = InputSource::from_args().unwrap_or_else(|e| {
+        eprintln!("Failed to process arguments: {}", e);
+        process::exit(1);
+    });
+
+    let input = source.read(year, day).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    if source.is_puzzle() {
+        manifest::warn_if_modified(year, day, &input);
+    }
+    input
 }
 
 #[cfg(test)]
@@ -96,30 +641,144 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_solutions_are_ordered_by_day() {
+        let days: Vec<(u16, u8)> = days::SOLUTIONS.iter().map(|s| (s.year, s.day)).collect();
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_try_read_file() {
+        assert_eq!(
+            try_read_file("examples", 2022, 1).unwrap(),
+            read_file("examples", 2022, 1)
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        let err = try_read_file("examples", 2022, 99).unwrap_err();
+        assert!(matches!(err, ReadError::Missing { day: 99, .. }));
+
+        let err = try_read_file("inputs", 2022, 99).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("Run `cargo download 99` to fetch your puzzle input."));
+
+        let err = try_read_file("inputs", 2015, 99).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("Run `cargo download 99 --year 2015` to fetch your puzzle input."));
+    }
+
+    #[test]
+    fn test_input_source_args() {
+        let parse = |args: &[&str]| {
+            InputSource::parse(pico_args::Arguments::from_vec(
+                args.iter().map(Into::into).collect(),
+            ))
+        };
+
+        assert_eq!(parse(&[]).unwrap(), InputSource::Puzzle);
+        assert_eq!(parse(&["--example"]).unwrap(), InputSource::Example);
+        assert_eq!(parse(&["-"]).unwrap(), InputSource::Stdin);
+        assert_eq!(parse(&["--input", "-"]).unwrap(), InputSource::Stdin);
+        assert_eq!(
+            parse(&["--part", "1", "--input", "in.txt"]).unwrap(),
+            InputSource::Path("in.txt".into())
         );
+        assert!(parse(&["--input", "in.txt", "-"]).is_err());
+        assert!(parse(&["--example", "--input", "-"]).is_err());
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+    #[test]
+    fn test_layout() {
+        assert_eq!(bin_name(2022, 5), "05");
+        assert_eq!(bin_name(2015, 5), "2015-05");
+        assert_eq!(
+            input_path("inputs", 2015, 5),
+            root_dir().join("src/2015/inputs/05.txt")
+        );
+        assert_eq!(
+            input_path("inputs", 2022, 5),
+            root_dir().join("src/inputs/05.txt")
         );
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        assert_eq!(parse_bin_name("05"), Some((2022, 5)));
+        assert_eq!(parse_bin_name("2015-05"), Some((2015, 5)));
+        assert_eq!(parse_bin_name("scaffold"), None);
+    }
+
+    #[test]
+    fn test_solver_output() {
+        assert_eq!(
+            Some(3).into_solver_output(),
+            SolverOutput::Solved("3".into())
         );
+        assert_eq!(None::<u32>.into_solver_output(), SolverOutput::NotSolved);
+        assert_eq!(
+            "x".parse::<u32>().into_solver_output(),
+            SolverOutput::Failed("invalid digit found in string".into())
+        );
+
+        let error = helpers::parse::Span::new("x").error("bad input");
+        let result = PartResult::new(2022, 1, 1, Err::<u32, _>(error), Duration::ZERO);
+        assert_eq!(
+            (result.answer, result.error),
+            (None, Some("line 1, column 1: bad input".into()))
+        );
+    }
+
+    #[derive(Debug)]
+    struct Context(&'static str, ReadError);
+
+    impl Display for Context {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl Error for Context {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.1)
+        }
+    }
+
+    #[test]
+    fn test_solver_output_causes() {
+        let read_error = ReadError::Io {
+            path: "in.txt".into(),
+            source: io::Error::new(io::ErrorKind::PermissionDenied, "denied"),
+        };
+        let solver = || -> Result<u32, ErrorChain> { Err(Context("no grid", read_error))? };
+        assert_eq!(
+            solver().into_solver_output(),
+            SolverOutput::Failed("no grid: could not read \"in.txt\": denied".into())
+        );
+
+        let boxed: Result<u32, Box<dyn Error>> = Err("too few elves".into());
+        assert_eq!(
+            boxed.into_solver_output(),
+            SolverOutput::Failed("too few elves".into())
+        );
+    }
+
+    #[test]
+    fn test_part_result_json() {
+        let result = PartResult::new(2022, 6, 2, Some(19), Duration::from_micros(1450));
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2022,"day":6,"part":2,"answer":"19","elapsed_ns":1450000}"#
+        );
+
+        let result = PartResult::new(2022, 6, 1, None::<u32>, Duration::from_nanos(74));
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"year":2022,"day":6,"part":1,"answer":null,"elapsed_ns":74}"#
+        );
+
+        // the `all` runner reads results back from its worker processes.
+        let verified = PartResult {
+            verdict: Some(Verdict::Match),
+            ..result
+        };
+        let json = serde_json::to_string(&verified).unwrap();
+        assert_eq!(serde_json::from_str::<PartResult>(&json).unwrap(), verified);
     }
 }
@@ -2,45 +2,326 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::Verdict;
+use advent_of_code::bench::BenchConfig;
+use advent_of_code::history::{self, History, HISTORY_FILE};
+use advent_of_code::worker::{self, DayError, DayRun, WorkerOptions};
+use advent_of_code::{
+    days, OutputFormat, PartResult, Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DEFAULT_YEAR,
+};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::{
+    process,
+    time::{Duration, Instant},
+};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+struct Args {
+    year: u16,
+    save: bool,
+    compare: bool,
+    verify: bool,
+    parallel: bool,
+    example: bool,
+    baseline: Option<String>,
+    threshold: f64,
+    timeout: Duration,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        save: args.contains("--save"),
+        compare: args.contains("--compare"),
+        verify: args.contains("--verify"),
+        parallel: args.contains("--parallel"),
+        example: args.contains("--example"),
+        baseline: args.opt_value_from_str("--baseline")?,
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        timeout: args
+            .opt_value_from_str("--timeout-ms")?
+            .map_or(worker::DEFAULT_TIMEOUT, Duration::from_millis),
+    })
+}
+
+/// Only passes on what [`worker::run_worker`] reads, runner flags like `--save` stay with the runner.
+fn worker_args(example: bool) -> Vec<String> {
+    let mut args = vec![];
+    if let Some(part) = advent_of_code::part_from_args() {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    if let Some(bench) = BenchConfig::from_args() {
+        args.extend(bench.to_args());
+    }
+    if example {
+        args.push("--example".to_string());
+    }
+    args
+}
+
+fn print_day(run: &DayRun, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        run.results.iter().for_each(|result| result.print(format));
+        if let Some(error) = &run.error {
+            let record = serde_json::json!({
+                "year": run.year,
+                "day": run.day,
+                "error": error.kind(),
+                "message": error.message(),
+                "stderr": error.stderr(),
+            });
+            println!("{}", record);
+        }
+        return;
+    }
+
+    println!("----------");
+    println!("{}| Day {:02} |{}", ANSI_BOLD, run.day, ANSI_RESET);
+    println!("----------");
+
+    run.results.iter().for_each(|result| result.print(format));
+
+    match &run.error {
+        Some(DayError::Input(e)) => println!("Not solved: {}", e),
+        Some(error) => {
+            println!("❗ {}", error.message());
+            for line in error.stderr().lines() {
+                println!("  {}{}{}", ANSI_ITALIC, line, ANSI_RESET);
+            }
+        }
+        None => {}
+    }
+}
+
+/// Runs days on all cores and passes them to `on_done` in day order.
+fn run_parallel(solutions: &[&Solution], options: &WorkerOptions, mut on_done: impl FnMut(DayRun)) {
+    let next = AtomicUsize::new(0);
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(solutions.len());
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let (tx, next) = (tx.clone(), &next);
+            scope.spawn(move || {
+                while let Some(solution) = solutions.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    tx.send((solution.day, worker::run_day(solution, options)))
+                        .unwrap();
                 }
-            );
+            });
+        }
+        drop(tx);
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+        // days finish out of order, hold them back until all earlier days were passed on.
+        let mut pending = BTreeMap::new();
+        let mut order = solutions.iter().map(|s| s.day).peekable();
+        for (day, run) in rx {
+            pending.insert(day, run);
+            while let Some(run) = order.peek().and_then(|day| pending.remove(day)) {
+                order.next();
+                on_done(run);
             }
-        })
-        .sum();
+        }
+    });
+}
+
+/// How many of the selected parts ended up in which state.
+#[derive(Default)]
+struct Summary {
+    solved: usize,
+    not_solved: usize,
+    failed: usize,
+    timed_out: usize,
+}
+
+impl Summary {
+    fn add(&mut self, run: &DayRun) {
+        let selected = [1, 2]
+            .into_iter()
+            .filter(|part| advent_of_code::is_part_selected(*part))
+            .count();
+        let solved = run.results.iter().filter(|r| r.answer.is_some()).count();
+        let failed = run.results.iter().filter(|r| r.error.is_some()).count();
+        let missing = selected.saturating_sub(run.results.len());
+
+        self.solved += solved;
+        self.failed += failed;
+        self.not_solved += run.results.len() - solved - failed;
+        match run.error {
+            Some(DayError::Input(_)) | None => self.not_solved += missing,
+            Some(DayError::TimedOut { .. }) => self.timed_out += missing,
+            Some(DayError::Panicked { .. } | DayError::Crashed { .. }) => self.failed += missing,
+        }
+    }
+}
+
+fn run_all(
+    solutions: &[&Solution],
+    format: OutputFormat,
+    options: &WorkerOptions,
+    parallel: bool,
+) -> (Vec<PartResult>, Summary) {
+    let mut results = vec![];
+    let mut summary = Summary::default();
+    let mut on_done = |run: DayRun| {
+        print_day(&run, format);
+        summary.add(&run);
+        results.extend(run.results);
+    };
+
+    if parallel {
+        run_parallel(solutions, options, on_done);
+    } else {
+        solutions
+            .iter()
+            .for_each(|solution| on_done(worker::run_day(solution, options)));
+    }
+
+    (results, summary)
+}
+
+/// Compares `results` against the baseline in `history` and returns whether any part regressed.
+fn compare(history: &History, results: &[PartResult], args: &Args) -> bool {
+    let baseline = match args
+        .baseline
+        .as_deref()
+        .or_else(|| history.latest_revision())
+    {
+        Some(baseline) => baseline,
+        None => {
+            eprintln!(
+                "No baseline found in \"{}\". Record one with `cargo all --save`.",
+                HISTORY_FILE
+            );
+            process::exit(1);
+        }
+    };
 
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "{}Compared to {} (threshold: {}%):{}",
+        ANSI_BOLD, baseline, args.threshold, ANSI_RESET
     );
+
+    let comparisons = history.compare(baseline, results, args.threshold);
+    for c in &comparisons {
+        println!(
+            "{} Day {:02} Part {}: {:.2?} -> {:.2?} ({:+.1}%)",
+            if c.regressed { "❌" } else { "✅" },
+            c.day,
+            c.part,
+            c.baseline,
+            c.current,
+            c.change
+        );
+    }
+
+    comparisons.iter().any(|c| c.regressed)
+}
+
+/// Compares against and/or records into the benchmark history. Returns whether any part regressed.
+fn update_history(results: &[PartResult], args: &Args) -> bool {
+    let mut history = match History::load(history::history_path()) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", HISTORY_FILE, e);
+            process::exit(1);
+        }
+    };
+
+    let regressed = args.compare && compare(&history, results, args);
+
+    if args.save {
+        let revision = history::git_revision().unwrap_or_else(|| "unknown".into());
+        history.record(&revision, results);
+        if let Err(e) = history.save(history::history_path()) {
+            eprintln!("Failed to write \"{}\": {}", HISTORY_FILE, e);
+            process::exit(1);
+        }
+        println!("Saved timings for {} to \"{}\".", revision, HISTORY_FILE);
+    }
+
+    regressed
+}
+
+fn main() {
+    if let Some(id) = worker::worker_id() {
+        worker::run_worker(&id);
+        return;
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let solutions: Vec<&Solution> = days::SOLUTIONS
+        .iter()
+        .filter(|solution| solution.year == args.year)
+        .collect();
+    if solutions.is_empty() {
+        eprintln!(
+            "No days of {} are registered in \"src/days.rs\".",
+            args.year
+        );
+        process::exit(1);
+    }
+
+    let format = OutputFormat::from_env();
+    let timer = Instant::now();
+    let options = WorkerOptions {
+        timeout: args.timeout,
+        args: worker_args(args.example),
+    };
+    let (results, summary) = run_all(&solutions, format, &options, args.parallel);
+    let wall_clock = timer.elapsed();
+
+    if format == OutputFormat::Pretty {
+        let total: Duration = results.iter().map(|r| r.elapsed()).sum();
+        println!(
+            "{}Total:{} {}{:.2}ms (wall-clock: {:.2}ms){}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            wall_clock.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+        println!(
+            "{}Parts:{} {} solved, {} not solved, {} failed, {} timed out",
+            ANSI_BOLD,
+            ANSI_RESET,
+            summary.solved,
+            summary.not_solved,
+            summary.failed,
+            summary.timed_out
+        );
+    }
+
+    let mismatched = results.iter().any(|r| r.verdict == Some(Verdict::Mismatch));
+
+    if args.verify && mismatched {
+        eprintln!(
+            "Some answers differ from the known-good answers in \"{}\".",
+            advent_of_code::year_dir(args.year)
+                .join("answers")
+                .display()
+        );
+    }
+
+    let regressed = (args.save || args.compare) && update_history(&results, &args);
+
+    let crashed = summary.failed > 0 || summary.timed_out > 0;
+
+    if crashed || regressed || (args.verify && mismatched) {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,153 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A downloaded input as recorded in the manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputRecord {
+    pub day: u8,
+    pub sha256: String,
+    /// Seconds since the unix epoch.
+    pub downloaded_at: u64,
+}
+
+/// How an input differs from the downloaded version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modification {
+    TrailingNewlineStripped,
+    LineEndingsChanged,
+    Edited,
+}
+
+impl Display for Modification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Modification::TrailingNewlineStripped => write!(f, "its trailing newline was removed"),
+            Modification::LineEndingsChanged => write!(f, "its line endings were changed to CRLF"),
+            Modification::Edited => write!(f, "it was edited"),
+        }
+    }
+}
+
+/// Checksums and download times of the inputs in `src/<year>/inputs/`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    inputs: Vec<InputRecord>,
+}
+
+pub fn sha256(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+impl Manifest {
+    pub fn path(year: u16) -> PathBuf {
+        crate::year_dir(year).join("inputs").join("manifest.json")
+    }
+
+    /// Loads the manifest of `year`, starting a new one if the file does not exist yet.
+    pub fn load(year: u16) -> io::Result<Self> {
+        match fs::read_to_string(Manifest::path(year)) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Manifest::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, year: u16) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self).unwrap();
+        fs::write(Manifest::path(year), contents + "\n")
+    }
+
+    pub fn get(&self, day: u8) -> Option<&InputRecord> {
+        self.inputs.iter().find(|record| record.day == day)
+    }
+
+    /// Records a freshly downloaded input.
+    pub fn record(&mut self, day: u8, contents: &str) {
+        let downloaded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        self.inputs.retain(|record| record.day != day);
+        self.inputs.push(InputRecord {
+            day,
+            sha256: sha256(contents),
+            downloaded_at,
+        });
+        self.inputs.sort_by_key(|record| record.day);
+    }
+
+    /// Compares `contents` to the downloaded input of `day`. Returns `None` if unchanged or unknown.
+    pub fn check(&self, day: u8, contents: &str) -> Option<Modification> {
+        let record = self.get(day)?;
+        if sha256(contents) == record.sha256 {
+            None
+        } else if sha256(&format!("{}\n", contents)) == record.sha256 {
+            Some(Modification::TrailingNewlineStripped)
+        } else if sha256(&contents.replace("\r\n", "\n")) == record.sha256 {
+            Some(Modification::LineEndingsChanged)
+        } else {
+            Some(Modification::Edited)
+        }
+    }
+}
+
+/// Prints a warning if the puzzle input of `day` differs from the downloaded one.
+pub fn warn_if_modified(year: u16, day: u8, contents: &str) {
+    let modification = Manifest::load(year)
+        .ok()
+        .and_then(|manifest| manifest.check(day, contents));
+
+    if let Some(modification) = modification {
+        eprintln!(
+            "⚠️  The input of day {} differs from the downloaded one: {}. Run `{} --force` to restore it.",
+            day,
+            modification,
+            crate::download_command(year, day)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            sha256("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut manifest = Manifest::default();
+        manifest.record(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+
+        assert_eq!(manifest.check(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"), None);
+        assert_eq!(
+            manifest.check(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb"),
+            Some(Modification::TrailingNewlineStripped)
+        );
+        assert_eq!(
+            manifest.check(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\n"),
+            Some(Modification::LineEndingsChanged)
+        );
+        assert_eq!(manifest.check(6, "abc\n"), Some(Modification::Edited));
+        assert_eq!(manifest.check(7, "abc\n"), None);
+    }
+}
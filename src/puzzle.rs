@@ -0,0 +1,550 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+
+const VOID_ELEMENTS: [&str; 6] = ["br", "hr", "img", "input", "link", "meta"];
+
+/// A node of a parsed HTML document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+}
+
+impl Node {
+    fn is_element(&self, tag: &str) -> bool {
+        matches!(self, Node::Element { name, .. } if name == tag)
+    }
+
+    fn children(&self) -> &[Node] {
+        match self {
+            Node::Element { children, .. } => children,
+            Node::Text(_) => &[],
+        }
+    }
+
+    fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+
+    /// The concatenated text of this node and its descendants.
+    pub fn text(&self) -> String {
+        match self {
+            Node::Text(text) => text.clone(),
+            Node::Element { children, .. } => children.iter().map(Node::text).collect(),
+        }
+    }
+}
+
+/// Returns all elements named `tag` in document order, not descending into matches.
+pub fn find_all<'a>(nodes: &'a [Node], tag: &str) -> Vec<&'a Node> {
+    find_where(nodes, &|node| node.is_element(tag))
+}
+
+fn find_where<'a>(nodes: &'a [Node], predicate: &impl Fn(&Node) -> bool) -> Vec<&'a Node> {
+    nodes
+        .iter()
+        .flat_map(|node| {
+            if predicate(node) {
+                vec![node]
+            } else {
+                find_where(node.children(), predicate)
+            }
+        })
+        .collect()
+}
+
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..].find(';').map(|end| &rest[1..=end]);
+        let replacement = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        });
+
+        match (entity, replacement) {
+            (Some(entity), Some(c)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn parse_attrs(mut source: &str) -> Vec<(String, String)> {
+    let mut attrs = vec![];
+
+    loop {
+        source = source.trim_start();
+        let name_end = source
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(source.len());
+        if name_end == 0 {
+            return attrs;
+        }
+        let name = source[..name_end].to_ascii_lowercase();
+        source = source[name_end..].trim_start();
+
+        let value = match source.strip_prefix('=') {
+            Some(rest) => {
+                let rest = rest.trim_start();
+                let (value, remainder) = match rest.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let end = rest[1..].find(quote).map_or(rest.len(), |end| end + 1);
+                        (&rest[1..end], rest.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                        (&rest[..end], &rest[end..])
+                    }
+                };
+                source = remainder;
+                decode_entities(value)
+            }
+            None => String::new(),
+        };
+
+        attrs.push((name, value));
+    }
+}
+
+/// An element whose closing tag was not reached yet.
+#[derive(Default)]
+struct OpenElement {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+fn close(stack: &mut Vec<OpenElement>) {
+    let OpenElement {
+        name,
+        attrs,
+        children,
+    } = stack.pop().unwrap();
+    stack.last_mut().unwrap().children.push(Node::Element {
+        name,
+        attrs,
+        children,
+    });
+}
+
+/// Parses an HTML document leniently. Unclosed elements are closed at the end of their parent.
+pub fn parse_html(html: &str) -> Vec<Node> {
+    // the first entry holds the document's top-level nodes.
+    let mut stack = vec![OpenElement::default()];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            let name = tag[..end].trim().to_ascii_lowercase();
+            if let Some(depth) = stack.iter().rposition(|open| open.name == name) {
+                while stack.len() > depth.max(1) {
+                    close(&mut stack);
+                }
+            }
+            rest = tag.get(end + 1..).unwrap_or("");
+        } else if rest
+            .strip_prefix('<')
+            .is_some_and(|tag| tag.starts_with(|c: char| c.is_ascii_alphabetic()))
+        {
+            let end = rest.find('>').unwrap_or(rest.len());
+            let tag = &rest[1..end];
+            let self_closing = tag.ends_with('/');
+            let tag = tag.trim_end_matches('/');
+            let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+            let name = tag[..name_end].to_ascii_lowercase();
+            let attrs = parse_attrs(&tag[name_end..]);
+            rest = rest.get(end + 1..).unwrap_or("");
+
+            let is_void = self_closing || VOID_ELEMENTS.contains(&name.as_str());
+            stack.push(OpenElement {
+                name,
+                attrs,
+                children: vec![],
+            });
+            if is_void {
+                close(&mut stack);
+            }
+        } else {
+            // the text starts with at least one character, which may be a `<` that opens no tag.
+            let first = rest.chars().next().unwrap().len_utf8();
+            let end = rest[first..]
+                .find('<')
+                .map_or(rest.len(), |end| end + first);
+            let text = decode_entities(&rest[..end]);
+            stack.last_mut().unwrap().children.push(Node::Text(text));
+            rest = &rest[end..];
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    stack.pop().unwrap().children
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('*', "\\*")
+        .replace('`', "\\`")
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_was_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_was_space {
+                collapsed.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            collapsed.push(c);
+            last_was_space = false;
+        }
+    }
+    collapsed
+}
+
+fn absolute_url(href: &str, base_url: &str) -> String {
+    if href.starts_with('/') {
+        format!("{}{}", base_url.trim_end_matches('/'), href)
+    } else {
+        href.to_string()
+    }
+}
+
+fn render_inline(nodes: &[Node], base_url: &str) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => escape_markdown(&collapse_whitespace(text)),
+            Node::Element { name, children, .. } => match name.as_str() {
+                // answers are formatted as `<code><em>42</em></code>`, some emphasis as `<em><code>`.
+                "code" | "em"
+                    if children.len() == 1
+                        && (children[0].is_element("code") || children[0].is_element("em")) =>
+                {
+                    format!("**`{}`**", node.text())
+                }
+                "code" => format!("`{}`", node.text()),
+                "em" => format!("**{}**", render_inline(children, base_url).trim()),
+                "a" => format!(
+                    "[{}]({})",
+                    render_inline(children, base_url),
+                    absolute_url(node.attr("href").unwrap_or_default(), base_url)
+                ),
+                "br" => "  \n".to_string(),
+                _ => render_inline(children, base_url),
+            },
+        })
+        .collect()
+}
+
+fn render_blocks(nodes: &[Node], base_url: &str, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) if text.trim().is_empty() => {}
+            Node::Text(_) => {
+                out.push_str(render_inline(std::slice::from_ref(node), base_url).trim());
+                out.push_str("\n\n");
+            }
+            Node::Element { name, children, .. } => match name.as_str() {
+                "h2" => {
+                    let title = node.text();
+                    let title = title.trim().trim_matches('-').trim();
+                    out.push_str(&format!("## {}\n\n", title));
+                }
+                "p" => {
+                    out.push_str(render_inline(children, base_url).trim());
+                    out.push_str("\n\n");
+                }
+                "pre" => {
+                    out.push_str("```\n");
+                    out.push_str(node.text().trim_end_matches('\n'));
+                    out.push_str("\n```\n\n");
+                }
+                "ul" | "ol" => {
+                    for item in children.iter().filter(|child| child.is_element("li")) {
+                        out.push_str("- ");
+                        out.push_str(render_inline(item.children(), base_url).trim());
+                        out.push('\n');
+                    }
+                    out.push('\n');
+                }
+                _ => render_blocks(children, base_url, out),
+            },
+        }
+    }
+}
+
+/// Converts the `<article>` sections of a puzzle page to Markdown.
+/// Part two is included once it is unlocked. Returns `None` if the page contains no puzzle.
+pub fn to_markdown(html: &str, base_url: &str) -> Option<String> {
+    let nodes = parse_html(html);
+    let articles = find_all(&nodes, "article");
+    if articles.is_empty() {
+        return None;
+    }
+
+    let mut markdown = String::new();
+    for article in articles {
+        render_blocks(article.children(), base_url, &mut markdown);
+    }
+    Some(markdown.trim_end().to_string() + "\n")
+}
+
+/// Returns the contents of all `<pre><code>` blocks of the puzzle, which usually hold the examples.
+pub fn code_blocks(html: &str) -> Vec<String> {
+    let nodes = parse_html(html);
+    find_all(&nodes, "article")
+        .into_iter()
+        .flat_map(|article| find_all(article.children(), "pre"))
+        .filter(|pre| pre.children().iter().any(|child| child.is_element("code")))
+        .map(Node::text)
+        .collect()
+}
+
+/// Guesses the example's answers of both parts: the last emphasized code in each part's description,
+/// e.g. `<code><em>24000</em></code>`.
+pub fn example_answers(html: &str) -> [Option<String>; 2] {
+    let nodes = parse_html(html);
+    let articles = find_all(&nodes, "article");
+
+    let last_answer = |article: &&Node| {
+        let is_answer = |node: &Node| {
+            (node.is_element("code") || node.is_element("em"))
+                && node.children().len() == 1
+                && (node.children()[0].is_element("em") || node.children()[0].is_element("code"))
+        };
+        find_where(article.children(), &is_answer)
+            .pop()
+            .map(|node| node.text().trim().to_string())
+    };
+
+    [
+        articles.first().and_then(last_answer),
+        articles.get(1).and_then(last_answer),
+    ]
+}
+
+/// Replaces the `None` placeholder that `cargo scaffold` puts into the test of `solver`
+/// with `answer`. Returns `None` if the placeholder is not there anymore.
+pub fn seed_test_assertion(module: &str, solver: &str, answer: &str) -> Option<String> {
+    let placeholder = format!("assert_eq!({}(&input), None);", solver);
+    if !module.contains(&placeholder) {
+        return None;
+    }
+
+    let expected = if answer.parse::<i64>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("{:?}.into()", answer)
+    };
+    let assertion = format!("assert_eq!({}(&input), Some({}));", solver, expected);
+    Some(module.replacen(&placeholder, &assertion, 1))
+}
+
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    crate::year_dir(year)
+        .join("puzzles")
+        .join(format!("{:02}.md", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/puzzle_01.html");
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("a &lt; b &amp;&amp; c"), "a < b && c");
+        assert_eq!(decode_entities("&#39;&#x41;&quot;"), "'A\"");
+        assert_eq!(
+            decode_entities("fish & chips &unknown;"),
+            "fish & chips &unknown;"
+        );
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let nodes = parse_html(r#"<p class="x">a<br>b <em>c</em></p>"#);
+        assert_eq!(
+            nodes,
+            vec![Node::Element {
+                name: "p".into(),
+                attrs: vec![("class".into(), "x".into())],
+                children: vec![
+                    Node::Text("a".into()),
+                    Node::Element {
+                        name: "br".into(),
+                        attrs: vec![],
+                        children: vec![]
+                    },
+                    Node::Text("b ".into()),
+                    Node::Element {
+                        name: "em".into(),
+                        attrs: vec![],
+                        children: vec![Node::Text("c".into())]
+                    },
+                ]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_html_non_ascii_text() {
+        let nodes = parse_html("<p>—</p><em>é</em>< ü");
+        assert_eq!(
+            nodes,
+            vec![
+                Node::Element {
+                    name: "p".into(),
+                    attrs: vec![],
+                    children: vec![Node::Text("—".into())]
+                },
+                Node::Element {
+                    name: "em".into(),
+                    attrs: vec![],
+                    children: vec![Node::Text("é".into())]
+                },
+                Node::Text("< ü".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = to_markdown(FIXTURE, "https://adventofcode.com").unwrap();
+        assert_eq!(
+            markdown,
+            r#"## Day 1: Calorie Counting
+
+Santa's reindeer typically eat regular reindeer food, but they need a lot of [magical energy](https://adventofcode.com/2018/day/25) to deliver presents on Christmas.
+
+The Elves take turns writing down the number of **Calories** contained by the various meals, snacks, rations, etc. that they've brought with them, one item per line.
+
+For example, suppose the Elves finish writing their items' Calories and end up with the following list:
+
+```
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+```
+
+This list represents the Calories of the food carried by five Elves:
+
+- The first Elf is carrying food with `1000`, `2000`, and `3000` Calories, a total of **`6000`** Calories.
+- The second Elf is carrying one food item with **`4000`** Calories.
+- The third Elf is carrying food with `5000` and `6000` Calories, a total of **`11000`** Calories.
+- The fourth Elf is carrying food with `7000`, `8000`, and `9000` Calories, a total of **`24000`** Calories.
+- The fifth Elf is carrying one food item with **`10000`** Calories.
+
+In case the Elves get hungry and need extra snacks, they need to know which Elf to ask: they'd like to know how many Calories are being carried by the Elf carrying the **most** Calories. In the example above, this is **`24000`** (carried by the fourth Elf).
+
+Find the Elf carrying the most Calories. **How many total Calories is that Elf carrying?**
+
+## Part Two
+
+By the time you calculate the answer to the Elves' question, they've already realized that the Elf carrying the most Calories of food might eventually **run out of snacks**.
+
+Find the top three Elves carrying the most Calories. **How many Calories are those Elves carrying in total?** (The answer is `a < b && c`.)
+"#
+        );
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        assert_eq!(
+            code_blocks(FIXTURE),
+            vec!["1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n"]
+        );
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(example_answers(FIXTURE), [Some("24000".into()), None]);
+    }
+
+    #[test]
+    fn test_seed_test_assertion() {
+        let module = "assert_eq!(part_one(&input), None);\nassert_eq!(part_two(&input), None);";
+        assert_eq!(
+            seed_test_assertion(module, "part_one", "24000"),
+            Some(
+                "assert_eq!(part_one(&input), Some(24000));\nassert_eq!(part_two(&input), None);"
+                    .into()
+            )
+        );
+        assert_eq!(
+            seed_test_assertion(module, "part_two", "CMZ"),
+            Some("assert_eq!(part_one(&input), None);\nassert_eq!(part_two(&input), Some(\"CMZ\".into()));".into())
+        );
+        assert_eq!(
+            seed_test_assertion("assert_eq!(part_one(&input), Some(1));", "part_one", "2"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_without_puzzle() {
+        assert_eq!(
+            to_markdown("<html><body>Please log in.</body></html>", ""),
+            None
+        );
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Renders the results table between the `<!--- advent_readme_stars table --->` markers of the README.
+
+use std::{ops::Range, time::Duration};
+
+pub const README_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Whether the parts of a day are solved, and how long they took in the latest recorded run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: u8,
+    pub stars: [bool; 2],
+    pub timings: [Option<Duration>; 2],
+}
+
+fn benchmark_cell(timings: &[Option<Duration>; 2]) -> String {
+    match timings {
+        [None, None] => " ".to_string(),
+        [part_one, part_two] => {
+            let format = |timing: &Option<Duration>| {
+                timing.map_or("-".to_string(), |timing| format!("{:.2?}", timing))
+            };
+            format!("{} / {}", format(part_one), format(part_two))
+        }
+    }
+}
+
+/// Renders the table of `year`, including both markers.
+pub fn render_table(year: u16, days: &[DayStatus]) -> String {
+    let mut table = format!(
+        "{}\n## {} Results\n\n| Day | Part 1 | Part 2 | Benchmark |\n| :---: | :---: | :---: | :---: |\n",
+        README_MARKER, year
+    );
+
+    for status in days {
+        let star = |solved: bool| if solved { "⭐" } else { " " };
+        table += &format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} | {} |\n",
+            status.day,
+            year,
+            status.day,
+            star(status.stars[0]),
+            star(status.stars[1]),
+            benchmark_cell(&status.timings)
+        );
+    }
+
+    table + README_MARKER
+}
+
+/// The table in `readme`, including both markers.
+fn table_range(readme: &str) -> Option<Range<usize>> {
+    let start = readme.find(README_MARKER)?;
+    let end = start + README_MARKER.len();
+    let end = end + readme[end..].find(README_MARKER)? + README_MARKER.len();
+    Some(start..end)
+}
+
+/// Replaces the table between the markers in `readme`. Returns `None` if the markers are missing.
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let range = table_range(readme)?;
+    let mut updated = readme.to_string();
+    updated.replace_range(range, table);
+    Some(updated)
+}
+
+/// The days of `year` listed in the current table of `readme`, with the stars they show.
+pub fn parse_stars(readme: &str, year: u16) -> Vec<(u8, [bool; 2])> {
+    let table = match table_range(readme) {
+        Some(range) => &readme[range],
+        None => return vec![],
+    };
+
+    table
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+            let (day, link) = cells.get(1)?.strip_prefix("[Day ")?.split_once(']')?;
+            if link != format!("(https://adventofcode.com/{}/day/{})", year, day) {
+                return None;
+            }
+            let star = |index: usize| cells.get(index) == Some(&"⭐");
+            Some((day.parse().ok()?, [star(2), star(3)]))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table() {
+        let days = [
+            DayStatus {
+                day: 1,
+                stars: [true, true],
+                timings: [
+                    Some(Duration::from_micros(25)),
+                    Some(Duration::from_nanos(9690)),
+                ],
+            },
+            DayStatus {
+                day: 2,
+                stars: [true, false],
+                timings: [Some(Duration::from_millis(3)), None],
+            },
+            DayStatus {
+                day: 3,
+                ..DayStatus::default()
+            },
+        ];
+
+        assert_eq!(
+            render_table(2022, &days),
+            "<!--- advent_readme_stars table --->
+## 2022 Results
+
+| Day | Part 1 | Part 2 | Benchmark |
+| :---: | :---: | :---: | :---: |
+| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ | 25.00µs / 9.69µs |
+| [Day 2](https://adventofcode.com/2022/day/2) | ⭐ |   | 3.00ms / - |
+| [Day 3](https://adventofcode.com/2022/day/3) |   |   |   |
+<!--- advent_readme_stars table --->"
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = "# AoC\n\n<!--- advent_readme_stars table --->\nold\n<!--- advent_readme_stars table --->\n\nFooter\n";
+        let table = render_table(2022, &[]);
+
+        let updated = replace_table(readme, &table).unwrap();
+        assert_eq!(updated, format!("# AoC\n\n{}\n\nFooter\n", table));
+        assert_eq!(replace_table(&updated, &table).unwrap(), updated);
+        assert_eq!(replace_table("# AoC\n", &table), None);
+    }
+
+    #[test]
+    fn test_parse_stars() {
+        let days = [
+            DayStatus {
+                day: 1,
+                stars: [true, true],
+                timings: [Some(Duration::from_micros(25)), None],
+            },
+            DayStatus {
+                day: 16,
+                stars: [true, false],
+                ..DayStatus::default()
+            },
+        ];
+        let readme = format!("# AoC\n\n{}\n", render_table(2022, &days));
+
+        assert_eq!(
+            parse_stars(&readme, 2022),
+            vec![(1, [true, true]), (16, [true, false])]
+        );
+        assert_eq!(parse_stars(&readme, 2021), vec![]);
+        assert_eq!(parse_stars("# AoC\n", 2022), vec![]);
+
+        let old_format = "<!--- advent_readme_stars table --->\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 3](https://adventofcode.com/2022/day/3) |   | ⭐ |\n<!--- advent_readme_stars table --->";
+        assert_eq!(parse_stars(old_format, 2022), vec![(3, [false, true])]);
+    }
+}
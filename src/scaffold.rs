@@ -0,0 +1,322 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Edits to the files that need to know about every day: the registry in `src/days.rs`,
+//! the results table in the README and the debug configurations in `.vscode/launch.json`.
+//! Every function returns `None` if there is nothing to change, so scaffolding is idempotent.
+
+use crate::readme::README_MARKER;
+
+/// The module a day binary is included as in `src/days.rs`, e.g. `day05` or `day2021_05`.
+fn module_name(bin_name: &str) -> String {
+    format!("day{}", bin_name.replace('-', "_"))
+}
+
+fn registry_entry(bin_name: &str) -> String {
+    format!(
+        "#[path = \"bin/{}.rs\"]\nmod {};\n",
+        bin_name,
+        module_name(bin_name)
+    )
+}
+
+fn solution_entry(year: u16, day: u8, bin_name: &str) -> String {
+    format!(
+        "    solution!({}, {}, {}),\n",
+        year,
+        day,
+        module_name(bin_name)
+    )
+}
+
+/// Inserts `line` before the first line in `lines` for which `is_after` holds, or after the last one.
+fn insert_sorted(
+    contents: &str,
+    range: (usize, usize),
+    line: &str,
+    is_after: impl Fn(&str) -> bool,
+) -> String {
+    let (start, end) = range;
+    let mut offset = start;
+    for existing in contents[start..end].split_inclusive('\n') {
+        if is_after(existing) {
+            break;
+        }
+        offset += existing.len();
+    }
+
+    let mut updated = contents.to_string();
+    updated.insert_str(offset, line);
+    updated
+}
+
+/// Year and day of a `#[path = "bin/<name>.rs"]` line.
+fn registry_id(line: &str) -> Option<(u16, u8)> {
+    let rest = line.trim_start().strip_prefix("#[path = \"bin/")?;
+    crate::parse_bin_name(rest.split_once(".rs")?.0)
+}
+
+/// Year and day of a `solution!(<year>, <day>, <module>),` line.
+fn solution_id(line: &str) -> Option<(u16, u8)> {
+    let rest = line.trim_start().strip_prefix("solution!(")?;
+    let mut args = rest.split(',').map(str::trim);
+    Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+}
+
+/// Adds a day to the module declarations and `SOLUTIONS` in `src/days.rs`.
+pub fn register_day(days: &str, year: u16, day: u8, bin_name: &str) -> Option<String> {
+    if days.contains(&registry_entry(bin_name)) {
+        return None;
+    }
+
+    // the module declarations end with the last `mod dayNN;`, or start right before `SOLUTIONS`.
+    let solutions = days.find("pub const SOLUTIONS")?;
+    let modules = days.find("#[path = ").unwrap_or(solutions);
+    let modules_end = match days[..solutions].rfind("\nmod day") {
+        Some(last) => last + 1 + days[last + 1..].find('\n')? + 1,
+        None => modules,
+    };
+    let days = insert_sorted(
+        days,
+        (modules, modules_end),
+        &registry_entry(bin_name),
+        |line| registry_id(line).is_some_and(|id| id > (year, day)),
+    );
+
+    let start = days.find("pub const SOLUTIONS")?;
+    let start = start + days[start..].find('\n')? + 1;
+    let end = start + days[start..].find("];")?;
+    Some(insert_sorted(
+        &days,
+        (start, end),
+        &solution_entry(year, day, bin_name),
+        |line| solution_id(line).is_some_and(|id| id > (year, day)),
+    ))
+}
+
+/// Removes a day from `src/days.rs`.
+pub fn unregister_day(days: &str, year: u16, day: u8, bin_name: &str) -> Option<String> {
+    if !days.contains(&registry_entry(bin_name)) {
+        return None;
+    }
+    Some(days.replacen(&registry_entry(bin_name), "", 1).replacen(
+        &solution_entry(year, day, bin_name),
+        "",
+        1,
+    ))
+}
+
+fn readme_row_prefix(year: u16, day: u8) -> String {
+    format!(
+        "| [Day {}](https://adventofcode.com/{}/day/{}) |",
+        day, year, day
+    )
+}
+
+fn readme_row_day(line: &str) -> Option<u8> {
+    let rest = line.strip_prefix("| [Day ")?;
+    rest.split_once(']')?.0.parse().ok()
+}
+
+/// Adds an empty row for `day` to the results table of the README, if the table is for `year`.
+pub fn add_readme_row(readme: &str, year: u16, day: u8) -> Option<String> {
+    if readme.contains(&readme_row_prefix(year, day)) {
+        return None;
+    }
+
+    let start = readme.find(README_MARKER)? + README_MARKER.len();
+    let end = start + readme[start..].find(README_MARKER)?;
+    if !readme[start..end].contains(&format!("## {} Results", year)) {
+        return None;
+    }
+
+    // leave every column after the day empty, e.g. the stars and benchmark.
+    let columns = readme[start..end]
+        .lines()
+        .find(|line| line.starts_with("| Day |"))
+        .map_or(3, |header| header.matches('|').count() - 1);
+    let row = format!(
+        "{}{}\n",
+        readme_row_prefix(year, day),
+        "   |".repeat(columns - 1)
+    );
+    Some(insert_sorted(readme, (start, end), &row, |line| {
+        readme_row_day(line).is_some_and(|d| d > day)
+    }))
+}
+
+/// Removes the row of `day` from the results table, unless it has stars already.
+pub fn remove_readme_row(readme: &str, year: u16, day: u8) -> Option<String> {
+    let start = readme.find(&readme_row_prefix(year, day))?;
+    let end = readme[start..]
+        .find('\n')
+        .map_or(readme.len(), |i| start + i + 1);
+    if readme[start..end].contains('⭐') {
+        return None;
+    }
+
+    let mut updated = readme.to_string();
+    updated.replace_range(start..end, "");
+    Some(updated)
+}
+
+fn launch_configurations(bin_name: &str) -> String {
+    let config = |name: String, args: &str| {
+        format!(
+            r#"        {{
+            "type": "lldb",
+            "request": "launch",
+            "name": "{name}",
+            "cargo": {{
+                "args": [{args}, "--bin={bin_name}", "--package=advent_of_code"],
+                "filter": {{
+                    "name": "{bin_name}",
+                    "kind": "bin"
+                }}
+            }},
+            "args": [],
+            "cwd": "${{workspaceFolder}}"
+        }}"#
+        )
+    };
+
+    [
+        config(format!("Debug executable '{}'", bin_name), r#""build""#),
+        config(
+            format!("Debug unit tests in executable '{}'", bin_name),
+            r#""test", "--no-run""#,
+        ),
+    ]
+    .join(",\n")
+}
+
+/// Adds debug configurations for a day binary and its tests to `.vscode/launch.json`.
+pub fn add_launch_configurations(launch: &str, bin_name: &str) -> Option<String> {
+    let configurations = launch_configurations(bin_name);
+    if launch.contains(&configurations) {
+        return None;
+    }
+
+    // insert after the last configuration, before the closing `]` of the list.
+    let list_end = launch.rfind(']')?;
+    let last = launch[..list_end].rfind('}');
+    let mut updated = launch.to_string();
+    match last {
+        Some(last) => updated.insert_str(last + 1, &format!(",\n{}", configurations)),
+        None => updated.insert_str(list_end, &format!("\n{}\n    ", configurations)),
+    }
+    Some(updated)
+}
+
+/// Removes the debug configurations of a day binary from `.vscode/launch.json`.
+pub fn remove_launch_configurations(launch: &str, bin_name: &str) -> Option<String> {
+    let configurations = launch_configurations(bin_name);
+    let with_separator = format!(",\n{}", configurations);
+    if launch.contains(&with_separator) {
+        Some(launch.replacen(&with_separator, "", 1))
+    } else if launch.contains(&configurations) {
+        Some(launch.replacen(&configurations, "", 1))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "use crate::{solution, Solution};
+
+#[path = \"bin/01.rs\"]
+mod day01;
+#[path = \"bin/03.rs\"]
+mod day03;
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(2022, 1, day01),
+    solution!(2022, 3, day03),
+];
+";
+
+    #[test]
+    fn test_register_day() {
+        let registered = register_day(DAYS, 2022, 2, "02").unwrap();
+        assert_eq!(
+            registered,
+            "use crate::{solution, Solution};
+
+#[path = \"bin/01.rs\"]
+mod day01;
+#[path = \"bin/02.rs\"]
+mod day02;
+#[path = \"bin/03.rs\"]
+mod day03;
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(2022, 1, day01),
+    solution!(2022, 2, day02),
+    solution!(2022, 3, day03),
+];
+"
+        );
+        assert_eq!(register_day(&registered, 2022, 2, "02"), None);
+        assert_eq!(unregister_day(&registered, 2022, 2, "02").unwrap(), DAYS);
+        assert_eq!(unregister_day(DAYS, 2022, 2, "02"), None);
+
+        let appended = register_day(DAYS, 2022, 10, "10").unwrap();
+        assert!(appended.contains("mod day03;\n#[path = \"bin/10.rs\"]\nmod day10;\n\npub"));
+        assert!(appended.contains("solution!(2022, 10, day10),\n];"));
+    }
+
+    #[test]
+    fn test_register_day_of_other_year() {
+        let registered = register_day(DAYS, 2021, 5, "2021-05").unwrap();
+        assert!(registered
+            .contains("\n#[path = \"bin/2021-05.rs\"]\nmod day2021_05;\n#[path = \"bin/01.rs\"]"));
+        assert!(
+            registered.contains("[\n    solution!(2021, 5, day2021_05),\n    solution!(2022, 1")
+        );
+        assert_eq!(
+            unregister_day(&registered, 2021, 5, "2021-05").unwrap(),
+            DAYS
+        );
+    }
+
+    #[test]
+    fn test_readme_rows() {
+        let readme = "# AoC\n\n<!--- advent_readme_stars table --->\n## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n<!--- advent_readme_stars table --->\n";
+
+        let added = add_readme_row(readme, 2022, 2).unwrap();
+        assert!(added.contains(
+            "| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n| [Day 2](https://adventofcode.com/2022/day/2) |   |   |\n<!---"
+        ));
+        assert_eq!(add_readme_row(&added, 2022, 2), None);
+        assert_eq!(remove_readme_row(&added, 2022, 2).unwrap(), readme);
+        assert_eq!(remove_readme_row(readme, 2022, 1), None);
+        assert_eq!(add_readme_row(readme, 2021, 2), None);
+        assert_eq!(add_readme_row("# No table\n", 2022, 2), None);
+
+        let table = crate::readme::render_table(2022, &[]);
+        assert!(add_readme_row(&table, 2022, 2)
+            .unwrap()
+            .contains("| [Day 2](https://adventofcode.com/2022/day/2) |   |   |   |\n<!---"));
+    }
+
+    #[test]
+    fn test_launch_configurations() {
+        let launch = include_str!("../.vscode/launch.json");
+
+        let added = add_launch_configurations(launch, "2021-16").unwrap();
+        assert!(added.contains("\"name\": \"Debug executable '2021-16'\""));
+        assert!(added.contains("[\"build\", \"--bin=2021-16\", \"--package=advent_of_code\"]"));
+        assert!(added.ends_with("        }\n    ]\n}\n"));
+        assert_eq!(add_launch_configurations(&added, "2021-16"), None);
+        assert_eq!(
+            remove_launch_configurations(&added, "2021-16").unwrap(),
+            launch
+        );
+        assert_eq!(remove_launch_configurations(launch, "2021-16"), None);
+    }
+}
@@ -0,0 +1,282 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{puzzle, regex};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Name of the log of submitted answers, which lives next to `Cargo.toml`.
+pub const SUBMISSIONS_FILE: &str = "submissions.json";
+
+/// The submission log of this project, so `cargo submit` finds earlier wrong answers from any subdirectory.
+pub fn submissions_path() -> PathBuf {
+    crate::root_dir().join(SUBMISSIONS_FILE)
+}
+
+/// Whether a wrong answer was too high or too low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part was solved already, or is not unlocked yet.
+    WrongLevel,
+    /// The response could not be understood. Holds the response's text.
+    Unknown(String),
+}
+
+fn parse_number(word: &str) -> Option<u64> {
+    let numbers = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    word.parse()
+        .ok()
+        .or_else(|| numbers.iter().position(|n| *n == word).map(|n| n as u64))
+}
+
+/// Parses the page returned after submitting an answer.
+pub fn parse_response(html: &str) -> Outcome {
+    let nodes = puzzle::parse_html(html);
+    let text = match puzzle::find_all(&nodes, "article").first() {
+        Some(article) => article.text(),
+        None => return Outcome::Unknown(html.trim().to_string()),
+    };
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        let wait = regex!(r"[Pp]lease wait (\w+) minutes?")
+            .captures(&text)
+            .and_then(|c| parse_number(&c[1]))
+            .map(|minutes| Duration::from_secs(minutes * 60));
+        Outcome::Incorrect { hint, wait }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = regex!(r"You have (?:(\d+)m ?)?(\d+)s left to wait")
+            .captures(&text)
+            .map(|c| {
+                let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                let seconds: u64 = c[2].parse().unwrap();
+                Duration::from_secs(minutes * 60 + seconds)
+            })
+            .unwrap_or(Duration::from_secs(60));
+        Outcome::RateLimited { wait }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// A wrong answer that was submitted before.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub hint: Option<Hint>,
+}
+
+/// Wrong answers submitted so far and how long the server asked to wait.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submissions {
+    wrong_answers: Vec<Submission>,
+    /// Seconds since the unix epoch until which no answer should be submitted.
+    wait_until: Option<u64>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+impl Submissions {
+    /// Loads the log at `path`, starting a new one if the file does not exist yet.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, contents + "\n")
+    }
+
+    /// How long to wait before the next answer may be submitted.
+    pub fn remaining_wait(&self) -> Option<Duration> {
+        self.wait_until
+            .map(|until| until.saturating_sub(now()))
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+    }
+
+    /// Finds a reason not to submit `answer`: it was rejected before,
+    /// or an earlier too-high / too-low hint already rules it out.
+    pub fn rejection(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<&Submission> {
+        let answer = answer.trim();
+        let value: Option<i128> = answer.parse().ok();
+
+        self.wrong_answers
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+            .find(|s| {
+                let previous: Option<i128> = s.answer.parse().ok();
+                s.answer == answer
+                    || match (s.hint, value, previous) {
+                        (Some(Hint::TooHigh), Some(value), Some(previous)) => value >= previous,
+                        (Some(Hint::TooLow), Some(value), Some(previous)) => value <= previous,
+                        _ => false,
+                    }
+            })
+    }
+
+    /// Records the outcome of submitting `answer`.
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &str, outcome: &Outcome) {
+        let wait = match outcome {
+            Outcome::Incorrect { hint, wait } => {
+                self.wrong_answers.push(Submission {
+                    year,
+                    day,
+                    part,
+                    answer: answer.trim().to_string(),
+                    hint: *hint,
+                });
+                *wait
+            }
+            Outcome::RateLimited { wait } => Some(*wait),
+            _ => None,
+        };
+        self.wait_until = wait.map(|wait| now() + wait.as_secs());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{mock, Client};
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.")),
+            Outcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer. Please wait 5 minutes before trying again."
+            )),
+            Outcome::Incorrect {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 34s left to wait.")),
+            Outcome::RateLimited {
+                wait: Duration::from_secs(94)
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+    }
+
+    #[test]
+    fn test_rejection() {
+        let mut submissions = Submissions::default();
+        let incorrect = |hint| Outcome::Incorrect { hint, wait: None };
+        submissions.record(2022, 1, 1, "100", &incorrect(Some(Hint::TooLow)));
+        submissions.record(2022, 1, 1, "500", &incorrect(Some(Hint::TooHigh)));
+        submissions.record(2022, 1, 2, "abc", &incorrect(None));
+
+        assert!(submissions.rejection(2022, 1, 1, "100").is_some());
+        assert!(submissions.rejection(2022, 1, 1, "99").is_some());
+        assert!(submissions.rejection(2022, 1, 1, "501").is_some());
+        assert!(submissions.rejection(2022, 1, 1, "300").is_none());
+        assert!(submissions.rejection(2022, 1, 2, "abc").is_some());
+        assert!(submissions.rejection(2022, 1, 2, "abd").is_none());
+        assert!(submissions.rejection(2021, 1, 1, "100").is_none());
+    }
+
+    #[test]
+    fn test_submit_to_mock_server() {
+        let (base_url, server) = mock::serve_once(
+            200,
+            &page("That's not the right answer; your answer is too low. Please wait one minute before trying again."),
+        );
+        let client = Client::new(&base_url, "abc123");
+
+        let response = client.post_answer(2022, 1, 2, "42").unwrap();
+        let outcome = parse_response(&response);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=42"));
+
+        let mut submissions = Submissions::default();
+        submissions.record(2022, 1, 2, "42", &outcome);
+        assert!(submissions.rejection(2022, 1, 2, "42").is_some());
+        assert!(submissions.remaining_wait().is_some());
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::regex;
+use regex::Captures;
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::PathBuf,
+};
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Used when `templates/default.rs` has been removed.
+const BUILTIN_TEMPLATE: &str = include_str!("../templates/default.rs");
+
+/// Values substituted for `{{day}}`, `{{day_padded}}`, `{{year}}`, `{{part_one_type}}` and `{{part_two_type}}`.
+pub struct Placeholders {
+    pub day: u8,
+    pub year: u16,
+    pub part_one_type: String,
+    pub part_two_type: String,
+}
+
+#[derive(Debug)]
+pub enum TemplateError {
+    NotFound {
+        name: String,
+        available: Vec<String>,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    UnknownPlaceholders(Vec<String>),
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::NotFound { name, available } => write!(
+                f,
+                "template \"{}\" does not exist, available templates: {}",
+                name,
+                available.join(", ")
+            ),
+            TemplateError::Io { path, source } => {
+                write!(f, "could not read \"{}\": {}", path.display(), source)
+            }
+            TemplateError::UnknownPlaceholders(names) => {
+                write!(f, "unknown placeholders: {}", names.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+pub fn templates_dir() -> PathBuf {
+    crate::root_dir().join("templates")
+}
+
+/// Names of the templates in `templates/`, sorted alphabetically.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(templates_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+
+    if !names.iter().any(|name| name == DEFAULT_TEMPLATE) {
+        names.push(DEFAULT_TEMPLATE.to_string());
+    }
+    names.sort();
+    names
+}
+
+pub fn load(name: &str) -> Result<String, TemplateError> {
+    let path = templates_dir().join(format!("{}.rs", name));
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            if name == DEFAULT_TEMPLATE {
+                Ok(BUILTIN_TEMPLATE.to_string())
+            } else {
+                Err(TemplateError::NotFound {
+                    name: name.to_string(),
+                    available: available(),
+                })
+            }
+        }
+        Err(source) => Err(TemplateError::Io { path, source }),
+    }
+}
+
+pub fn render(template: &str, placeholders: &Placeholders) -> Result<String, TemplateError> {
+    let mut unknown = vec![];
+
+    let rendered =
+        regex!(r"\{\{\s*(\w+)\s*\}\}").replace_all(template, |caps: &Captures| match &caps[1] {
+            "day" => placeholders.day.to_string(),
+            "day_padded" => format!("{:02}", placeholders.day),
+            "year" => placeholders.year.to_string(),
+            "part_one_type" => placeholders.part_one_type.clone(),
+            "part_two_type" => placeholders.part_two_type.clone(),
+            name => {
+                unknown.push(name.to_string());
+                caps[0].to_string()
+            }
+        });
+
+    if unknown.is_empty() {
+        Ok(rendered.into_owned())
+    } else {
+        Err(TemplateError::UnknownPlaceholders(unknown))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders() -> Placeholders {
+        Placeholders {
+            day: 7,
+            year: 2022,
+            part_one_type: "u64".to_string(),
+            part_two_type: "String".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let template = "// {{year}}/{{ day }} {{day_padded}}\nfn f() -> Option<{{part_one_type}}> {}\nfn g() -> Option<{{part_two_type}}> {}";
+        assert_eq!(
+            render(template, &placeholders()).unwrap(),
+            "// 2022/7 07\nfn f() -> Option<u64> {}\nfn g() -> Option<String> {}"
+        );
+    }
+
+    #[test]
+    fn test_render_unknown_placeholder() {
+        match render("{{day}} {{month}}", &placeholders()) {
+            Err(TemplateError::UnknownPlaceholders(names)) => assert_eq!(names, vec!["month"]),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_templates_render() {
+        for name in available() {
+            let template = load(&name).unwrap();
+            let rendered = render(&template, &placeholders()).unwrap();
+            assert!(rendered.contains("read_input(2022, 7)"), "{}", name);
+        }
+        assert!(matches!(
+            load("does-not-exist"),
+            Err(TemplateError::NotFound { .. })
+        ));
+    }
+}
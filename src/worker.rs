@@ -0,0 +1,227 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Runs days in child processes, so a panic, crash or hang of one solution only affects its own day.
+//! A binary becomes a worker when it is started with [`WORKER_FLAG`], see [`worker_id`].
+
+use crate::bench::BenchConfig;
+use crate::{days, OutputFormat, PartResult, Solution};
+use std::io::Read;
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+use std::{env, panic, process, thread};
+
+/// Passed to the child processes that run a single day, see [`run_worker`].
+pub const WORKER_FLAG: &str = "--worker";
+
+/// How long a day may run before its worker is killed, unless the runner is told otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Exit code of a worker that could not read the input of its day.
+pub const WORKER_NO_INPUT: i32 = 66;
+/// Exit code of a worker whose solution panicked, set by the panic hook of [`run_worker`].
+pub const WORKER_PANICKED: i32 = 70;
+
+/// Why a day did not produce results for all of its selected parts.
+pub enum DayError {
+    /// The worker could not read the input, with the reason it printed.
+    Input(String),
+    Panicked {
+        stderr: String,
+    },
+    /// The worker exited without panicking, e.g. after a stack overflow.
+    Crashed {
+        status: ExitStatus,
+        stderr: String,
+    },
+    TimedOut {
+        timeout: Duration,
+        stderr: String,
+    },
+}
+
+impl DayError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DayError::Input(_) => "not solved",
+            DayError::Panicked { .. } => "panicked",
+            DayError::Crashed { .. } => "crashed",
+            DayError::TimedOut { .. } => "timed out",
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            DayError::Input(e) => e.clone(),
+            DayError::Panicked { .. } => "Panicked".to_string(),
+            DayError::Crashed { status, .. } => format!("Crashed ({})", status),
+            DayError::TimedOut { timeout, .. } => format!("Timed out after {:.2?}", timeout),
+        }
+    }
+
+    pub fn stderr(&self) -> &str {
+        match self {
+            DayError::Input(_) => "",
+            DayError::Panicked { stderr }
+            | DayError::Crashed { stderr, .. }
+            | DayError::TimedOut { stderr, .. } => stderr,
+        }
+    }
+}
+
+/// The outcome of running all selected parts of a day.
+/// Parts that finished before a panic or timeout are kept in `results`.
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    pub results: Vec<PartResult>,
+    pub error: Option<DayError>,
+}
+
+/// How the workers of a run are started.
+pub struct WorkerOptions {
+    pub timeout: Duration,
+    /// Flags passed on to every worker, e.g. `--part 1` or `--bench`.
+    pub args: Vec<String>,
+}
+
+/// The day this process should run, if it was started as a worker with [`WORKER_FLAG`].
+pub fn worker_id() -> Option<String> {
+    env::args().skip_while(|arg| arg != WORKER_FLAG).nth(1)
+}
+
+/// Runs a single day in this process and prints its results as JSON lines.
+/// The parent reads them from stdout, so a panic, crash or hang only affects this day.
+pub fn run_worker(id: &str) {
+    // tell the parent that the solution panicked, whatever the panic message looks like.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        process::exit(WORKER_PANICKED);
+    }));
+
+    let solution = crate::parse_bin_name(id).and_then(|(year, day)| {
+        days::SOLUTIONS
+            .iter()
+            .find(|s| s.year == year && s.day == day)
+    });
+    let solution = match solution {
+        Some(solution) => solution,
+        None => {
+            eprintln!("Day {} is not registered in \"src/days.rs\".", id);
+            process::exit(1);
+        }
+    };
+
+    let (year, day) = (solution.year, solution.day);
+    let example = pico_args::Arguments::from_env().contains("--example");
+    let folder = if example { "examples" } else { "inputs" };
+    let input = crate::try_read_file(folder, year, day).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(WORKER_NO_INPUT);
+    });
+    if !example {
+        crate::manifest::warn_if_modified(year, day, &input);
+    }
+    let bench = BenchConfig::from_args();
+
+    for (part, solver) in solution.parts() {
+        if crate::is_part_selected(part) {
+            crate::run_part(year, day, part, solver, &input, bench.as_ref())
+                .verified()
+                .print(OutputFormat::Json);
+        }
+    }
+}
+
+/// Runs `command` and kills it after `timeout`. Returns its exit status, or `None` if it timed out,
+/// together with everything it wrote to stdout and stderr.
+fn run_with_timeout(
+    command: &mut Command,
+    timeout: Duration,
+) -> std::io::Result<(Option<ExitStatus>, String, String)> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // drain both pipes while waiting, a child blocked on a full pipe would never exit.
+    let read = |mut pipe: Box<dyn Read + Send>| {
+        thread::spawn(move || {
+            let mut output = String::new();
+            pipe.read_to_string(&mut output).ok();
+            output
+        })
+    };
+    let stdout = read(Box::new(child.stdout.take().unwrap()));
+    let stderr = read(Box::new(child.stderr.take().unwrap()));
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if started.elapsed() > timeout {
+            child.kill()?;
+            child.wait()?;
+            break None;
+        }
+        thread::sleep(Duration::from_millis(1));
+    };
+
+    Ok((
+        status,
+        stdout.join().unwrap_or_default(),
+        stderr.join().unwrap_or_default(),
+    ))
+}
+
+pub fn run_day(solution: &Solution, options: &WorkerOptions) -> DayRun {
+    let (year, day) = (solution.year, solution.day);
+    let run = |results, error| DayRun {
+        year,
+        day,
+        results,
+        error,
+    };
+
+    let mut command = Command::new(env::current_exe().expect("could not locate the runner"));
+    command
+        .args(&options.args)
+        .arg(WORKER_FLAG)
+        .arg(format!("{}-{:02}", year, day));
+
+    let timeout = options.timeout;
+    let (status, stdout, stderr) = match run_with_timeout(&mut command, timeout) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run day {}: {}", day, e);
+            process::exit(1);
+        }
+    };
+
+    // solutions may print to stdout themselves, only pick up the lines written by the worker.
+    let results: Vec<PartResult> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+
+    let error = match status {
+        None => Some(DayError::TimedOut { timeout, stderr }),
+        Some(status) if status.success() => {
+            eprint!("{}", stderr);
+            None
+        }
+        Some(status) if status.code() == Some(WORKER_NO_INPUT) => {
+            Some(DayError::Input(stderr.trim().to_string()))
+        }
+        Some(status) if status.code() == Some(WORKER_PANICKED) => {
+            Some(DayError::Panicked { stderr })
+        }
+        Some(status) => Some(DayError::Crashed { status, stderr }),
+    };
+
+    run(results, error)
+}
@@ -0,0 +1,30 @@
+pub fn part_one(_input: &str) -> Option<{{part_one_type}}> {
+    None
+}
+
+pub fn part_two(_input: &str) -> Option<{{part_two_type}}> {
+    None
+}
+
+fn main() {
+    let input = &advent_of_code::read_input({{year}}, {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_two(&input), None);
+    }
+}
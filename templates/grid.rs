@@ -0,0 +1,39 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use pathfinding::prelude::Matrix;
+
+fn parse(input: &str) -> Matrix<u8> {
+    Matrix::from_rows(input.lines().map(str::bytes)).unwrap()
+}
+
+pub fn part_one(input: &str) -> Option<{{part_one_type}}> {
+    let _grid = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{part_two_type}}> {
+    let _grid = parse(input);
+    None
+}
+
+fn main() {
+    let input = &advent_of_code::read_input({{year}}, {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_two(&input), None);
+    }
+}
@@ -0,0 +1,48 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use advent_of_code::helpers::regex;
+
+#[derive(Debug)]
+struct Line {}
+
+fn parse(input: &str) -> Vec<Line> {
+    input
+        .lines()
+        .map(|line| {
+            let _caps = regex!(r"^(.*)$").captures(line).unwrap();
+            Line {}
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<{{part_one_type}}> {
+    let _lines = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{part_two_type}}> {
+    let _lines = parse(input);
+    None
+}
+
+fn main() {
+    let input = &advent_of_code::read_input({{year}}, {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_two(&input), None);
+    }
+}